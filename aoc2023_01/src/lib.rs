@@ -0,0 +1,50 @@
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{Answer, Line, Overflow, Part, Solution};
+
+pub struct Day01;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day01>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day01, R>(Part::One, reader)
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Ok(input.lines().map(Into::into).collect())
+    }
+
+    fn part1(lines: &Self::Parsed) -> Result<Answer, Error> {
+        let mut total = 0u64;
+        for (i, line) in lines.iter().enumerate() {
+            let mut first_digit = Option::<u32>::default();
+            let mut last_digit = Option::<u32>::default();
+            for c in line.chars() {
+                let unicode = c as u32; // https://stackoverflow.com/a/54583437
+                if unicode >= '0' as u32 && unicode <= '9' as u32 {
+                    if first_digit.is_none() {
+                        first_digit = Some(unicode - '0' as u32);
+                    }
+                    last_digit = Some(unicode - '0' as u32);
+                }
+            }
+            let (Some(first_digit), Some(last_digit)) = (first_digit, last_digit) else {
+                return Err(Line::new(i + 1, line).error_here("no digit").into());
+            };
+            total = total
+                .checked_add(u64::from(first_digit * 10 + last_digit))
+                .ok_or(Overflow)?;
+        }
+        Ok(total.into())
+    }
+}
@@ -0,0 +1,75 @@
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{Answer, Line, Overflow, Part, Solution};
+
+pub struct Day01;
+
+/// Solves part 2 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day01>(Part::Two, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day01, R>(Part::Two, reader)
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Ok(input.lines().map(Into::into).collect())
+    }
+
+    fn part2(lines: &Self::Parsed) -> Result<Answer, Error> {
+        let mut total = 0u64;
+        for (i, line) in lines.iter().enumerate() {
+            let mut first_digit = Option::<u32>::default();
+            let mut last_digit = Option::<u32>::default();
+            let mut current = line.as_str();
+            while !current.is_empty() {
+                let c = current.chars().next().unwrap_or_default();
+                let unicode = c as u32;
+                let mut digit = Option::<u32>::default();
+                if current.starts_with("one") {
+                    digit = Some(1);
+                } else if current.starts_with("two") {
+                    digit = Some(2);
+                } else if current.starts_with("three") {
+                    digit = Some(3);
+                } else if current.starts_with("four") {
+                    digit = Some(4);
+                } else if current.starts_with("five") {
+                    digit = Some(5);
+                } else if current.starts_with("six") {
+                    digit = Some(6);
+                } else if current.starts_with("seven") {
+                    digit = Some(7);
+                } else if current.starts_with("eight") {
+                    digit = Some(8);
+                } else if current.starts_with("nine") {
+                    digit = Some(9);
+                } else if unicode >= '0' as u32 && unicode <= '9' as u32 {
+                    digit = Some(unicode - '0' as u32);
+                }
+                if let Some(digit) = digit {
+                    if first_digit.is_none() {
+                        first_digit = Some(digit);
+                    }
+                    last_digit = Some(digit);
+                }
+                current = &current[c.len_utf8()..];
+            }
+            let (Some(first_digit), Some(last_digit)) = (first_digit, last_digit) else {
+                return Err(Line::new(i + 1, line).error_here("no digit").into());
+            };
+            total = total
+                .checked_add(u64::from(first_digit * 10 + last_digit))
+                .ok_or(Overflow)?;
+        }
+        Ok(total.into())
+    }
+}
@@ -0,0 +1,129 @@
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, param, Answer, InputError, Line, Overflow, Param, Part, Solution};
+
+pub struct Day02;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day02>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day02, R>(Part::One, reader)
+}
+
+pub struct Game {
+    id: usize,
+    reveals: Vec<Reveal>,
+}
+
+#[derive(Default)]
+pub struct Reveal {
+    blue: i32,
+    green: i32,
+    red: i32,
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "red",
+            default: "12",
+            help: "red cubes in the bag",
+        },
+        Param {
+            name: "green",
+            default: "13",
+            help: "green cubes in the bag",
+        },
+        Param {
+            name: "blue",
+            default: "14",
+            help: "blue cubes in the bag",
+        },
+    ];
+
+    type Parsed = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut games = Vec::new();
+
+        for line in lines(input) {
+            games.push(parse_game(&line)?);
+        }
+
+        Ok(games)
+    }
+
+    fn part1(games: &Self::Parsed) -> Result<Answer, Error> {
+        let red = param::<Self, i32>("red")?;
+        let green = param::<Self, i32>("green")?;
+        let blue = param::<Self, i32>("blue")?;
+
+        let mut possible_id_sum = 0usize;
+
+        for game in games.iter() {
+            let mut game_possible = true;
+
+            for reveal in game.reveals.iter() {
+                let possible = reveal.blue <= blue && reveal.green <= green && reveal.red <= red;
+                game_possible = game_possible && possible;
+            }
+
+            if game_possible {
+                possible_id_sum = possible_id_sum.checked_add(game.id).ok_or(Overflow)?;
+            }
+        }
+
+        Ok(possible_id_sum.into())
+    }
+}
+
+/// Parses a game like `Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green`.
+fn parse_game(line: &Line) -> Result<Game, InputError> {
+    let (game, reveals) = line
+        .split_once(": ")
+        .ok_or_else(|| line.error_here("expected a game like Game 1: 3 blue, 4 red"))?;
+    let id = game
+        .strip_prefix("Game ")
+        .ok_or_else(|| line.error_at(game, "expected Game <id>"))?;
+    let id = line.parse::<usize>(id)?;
+    let reveals = reveals
+        .split("; ")
+        .map(|reveal| parse_reveal(line, reveal))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Game { id, reveals })
+}
+
+/// Parses a reveal like `1 red, 2 green, 6 blue`.
+fn parse_reveal(line: &Line, text: &str) -> Result<Reveal, InputError> {
+    let mut reveal = Reveal::default();
+
+    for cubes in text.split(", ") {
+        let (quantity, color) = cubes
+            .split_once(' ')
+            .ok_or_else(|| line.error_at(cubes, "expected cubes like 3 blue"))?;
+        let quantity = line.parse::<i32>(quantity)?;
+        match color {
+            "blue" => {
+                reveal.blue = quantity;
+            }
+            "green" => {
+                reveal.green = quantity;
+            }
+            "red" => {
+                reveal.red = quantity;
+            }
+            _ => {
+                return Err(line.error_at(color, format!("unknown color {:?}", color)));
+            }
+        }
+    }
+
+    Ok(reveal)
+}
@@ -0,0 +1,19 @@
+#[test]
+fn example() {
+    let answer = aoc2023_02::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "8");
+}
+
+#[test]
+fn malformed_input() {
+    use aoc2023_common::InputError;
+
+    let error = aoc2023_02::solve("1 2\n\n3 4\n").unwrap_err();
+    let error = error.downcast_ref::<InputError>().unwrap();
+    assert_eq!((error.line(), error.column()), (Some(1), None));
+
+    let error = aoc2023_02::solve("Game 1: 3 blue, 4 purple\n").unwrap_err();
+    let error = error.downcast_ref::<InputError>().unwrap();
+    assert_eq!((error.line(), error.column()), (Some(1), Some(19)));
+    assert_eq!(error.message(), "unknown color \"purple\"");
+}
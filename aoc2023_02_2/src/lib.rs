@@ -0,0 +1,110 @@
+use std::cmp::max;
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, Answer, CheckedIterator, InputError, Line, Overflow, Part, Solution};
+
+pub struct Day02;
+
+/// Solves part 2 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day02>(Part::Two, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day02, R>(Part::Two, reader)
+}
+
+#[derive(Default)]
+pub struct Reveal {
+    blue: i32,
+    green: i32,
+    red: i32,
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Vec<Reveal>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut games = Vec::new();
+
+        for line in lines(input) {
+            games.push(parse_game(&line)?);
+        }
+
+        Ok(games)
+    }
+
+    fn part2(games: &Self::Parsed) -> Result<Answer, Error> {
+        let mut power_total = 0i64;
+
+        for game in games.iter() {
+            let mut max_blue = 0;
+            let mut max_green = 0;
+            let mut max_red = 0;
+
+            for reveal in game.iter() {
+                max_blue = max(max_blue, reveal.blue);
+                max_green = max(max_green, reveal.green);
+                max_red = max(max_red, reveal.red);
+            }
+
+            let power = [max_blue, max_green, max_red]
+                .into_iter()
+                .map(i64::from)
+                .checked_product()?;
+
+            power_total = power_total.checked_add(power).ok_or(Overflow)?;
+        }
+
+        Ok(power_total.into())
+    }
+}
+
+/// Parses a game like `Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green` into its
+/// reveals; part 2 has no use for the id beyond checking it.
+fn parse_game(line: &Line) -> Result<Vec<Reveal>, InputError> {
+    let (game, reveals) = line
+        .split_once(": ")
+        .ok_or_else(|| line.error_here("expected a game like Game 1: 3 blue, 4 red"))?;
+    let id = game
+        .strip_prefix("Game ")
+        .ok_or_else(|| line.error_at(game, "expected Game <id>"))?;
+    line.parse::<usize>(id)?;
+
+    reveals
+        .split("; ")
+        .map(|reveal| parse_reveal(line, reveal))
+        .collect()
+}
+
+/// Parses a reveal like `1 red, 2 green, 6 blue`.
+fn parse_reveal(line: &Line, text: &str) -> Result<Reveal, InputError> {
+    let mut reveal = Reveal::default();
+
+    for cubes in text.split(", ") {
+        let (quantity, color) = cubes
+            .split_once(' ')
+            .ok_or_else(|| line.error_at(cubes, "expected cubes like 3 blue"))?;
+        let quantity = line.parse::<i32>(quantity)?;
+        match color {
+            "blue" => {
+                reveal.blue = quantity;
+            }
+            "green" => {
+                reveal.green = quantity;
+            }
+            "red" => {
+                reveal.red = quantity;
+            }
+            _ => {
+                return Err(line.error_at(color, format!("unknown color {:?}", color)));
+            }
+        }
+    }
+
+    Ok(reveal)
+}
@@ -0,0 +1,14 @@
+#[test]
+fn example() {
+    let answer = aoc2023_02_2::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "2286");
+}
+
+#[test]
+fn malformed_input() {
+    use aoc2023_common::InputError;
+
+    let error = aoc2023_02_2::solve("Game 1: 3 blue\nGame x: 4 red\n").unwrap_err();
+    let error = error.downcast_ref::<InputError>().unwrap();
+    assert_eq!((error.line(), error.column()), (Some(2), Some(6)));
+}
@@ -0,0 +1,78 @@
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{Answer, Grid, Overflow, Part, Solution};
+
+pub struct Day03;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day03>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day03, R>(Part::One, reader)
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Grid::parse(input)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<Answer, Error> {
+        let mut part_number_total = 0u64;
+
+        let mut in_part_number = false;
+        let mut is_part = false;
+        let mut part_number = 0u64;
+
+        macro_rules! end_part_number {
+            () => {
+                if in_part_number && is_part {
+                    part_number_total =
+                        part_number_total.checked_add(part_number).ok_or(Overflow)?;
+                }
+                in_part_number = false;
+                is_part = false;
+                part_number = 0;
+            };
+        }
+
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                let c = grid[(x, y)];
+
+                if !c.is_ascii_digit() {
+                    end_part_number!();
+                    continue;
+                }
+
+                let digit = u64::from(c - b'0');
+
+                in_part_number = true;
+                part_number = part_number
+                    .checked_mul(10)
+                    .and_then(|number| number.checked_add(digit))
+                    .ok_or(Overflow)?;
+
+                let near_symbol = grid
+                    .neighbours8(x, y)
+                    .any(|neighbour| is_symbol(grid[neighbour]));
+
+                is_part = is_part || near_symbol;
+            }
+            end_part_number!();
+        }
+
+        Ok(part_number_total.into())
+    }
+}
+
+fn is_symbol(c: u8) -> bool {
+    c != b'.' && !c.is_ascii_digit()
+}
@@ -0,0 +1,11 @@
+#[test]
+fn example() {
+    let answer = aoc2023_03::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "4361");
+}
+
+#[test]
+fn number_in_the_last_cell() {
+    let answer = aoc2023_03::solve("*.\n.7\n").unwrap();
+    assert_eq!(answer, "7");
+}
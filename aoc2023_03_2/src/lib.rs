@@ -0,0 +1,110 @@
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{Answer, Grid, Overflow, Part, Solution};
+
+pub struct Day03;
+
+/// Solves part 2 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day03>(Part::Two, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day03, R>(Part::Two, reader)
+}
+
+struct PotentialGear<const MAX_PARTS: usize = 2> {
+    num_parts: usize,
+    part_numbers: [u64; MAX_PARTS],
+}
+
+impl<const MAX_PARTS: usize> PotentialGear<MAX_PARTS> {
+    fn default() -> Self {
+        Self {
+            num_parts: 0,
+            part_numbers: [0; MAX_PARTS],
+        }
+    }
+
+    fn process_part(&mut self, part_number: u64) {
+        if self.num_parts < MAX_PARTS {
+            self.part_numbers[self.num_parts] = part_number;
+        }
+        self.num_parts += 1;
+    }
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Grid::parse(input)
+    }
+
+    fn part2(grid: &Self::Parsed) -> Result<Answer, Error> {
+        let mut potential_gears = HashMap::<(usize, usize), PotentialGear>::new();
+
+        let mut in_part_number = false;
+        let mut part_gears = HashSet::<(usize, usize)>::new();
+        let mut part_number = 0u64;
+
+        macro_rules! end_part_number {
+            () => {
+                if in_part_number {
+                    for gear_i in part_gears.iter() {
+                        let potential_gear = potential_gears
+                            .entry(*gear_i)
+                            .or_insert_with(PotentialGear::default);
+                        potential_gear.process_part(part_number);
+                    }
+                }
+                in_part_number = false;
+                part_gears.clear();
+                part_number = 0;
+            };
+        }
+
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                let c = grid[(x, y)];
+
+                if !c.is_ascii_digit() {
+                    end_part_number!();
+                    continue;
+                }
+
+                let digit = u64::from(c - b'0');
+
+                in_part_number = true;
+                part_number = part_number
+                    .checked_mul(10)
+                    .and_then(|number| number.checked_add(digit))
+                    .ok_or(Overflow)?;
+
+                for neighbour in grid.neighbours8(x, y) {
+                    if grid[neighbour] == b'*' {
+                        part_gears.insert(neighbour);
+                    }
+                }
+            }
+            end_part_number!();
+        }
+
+        let mut gear_ratio_total = 0u64;
+
+        for potential_gear in potential_gears.values() {
+            if potential_gear.num_parts == 2 {
+                let [first, second] = potential_gear.part_numbers;
+                let gear_ratio = first.checked_mul(second).ok_or(Overflow)?;
+                gear_ratio_total = gear_ratio_total.checked_add(gear_ratio).ok_or(Overflow)?;
+            }
+        }
+
+        Ok(gear_ratio_total.into())
+    }
+}
@@ -0,0 +1,11 @@
+#[test]
+fn example() {
+    let answer = aoc2023_03_2::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "467835");
+}
+
+#[test]
+fn number_in_the_last_cell() {
+    let answer = aoc2023_03_2::solve("2.\n*7\n").unwrap();
+    assert_eq!(answer, "14");
+}
@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, Answer, Overflow, Part, Solution};
+
+pub struct Day04;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day04>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day04, R>(Part::One, reader)
+}
+
+pub struct Card {
+    have: Vec<String>,
+    winning: HashSet<String>,
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut cards = Vec::new();
+
+        for line in lines(input) {
+            let start_i = line
+                .find(":")
+                .ok_or_else(|| line.error_here("colon not found"))?
+                + 1;
+            let Some((winning, have)) = &line[start_i..].split_once('|') else {
+                return Err(line.error(start_i, "| not found after the colon").into());
+            };
+            let winning = winning
+                .split(" ")
+                .filter(|s| !s.is_empty())
+                .map(Into::into)
+                .collect::<HashSet<_>>();
+            let have = have
+                .split(' ')
+                .filter(|s| !s.is_empty())
+                .map(Into::into)
+                .collect::<Vec<_>>();
+            cards.push(Card { have, winning });
+        }
+
+        Ok(cards)
+    }
+
+    fn part1(cards: &Self::Parsed) -> Result<Answer, Error> {
+        let mut total_points = 0u64;
+
+        for card in cards.iter() {
+            let mut points = 0u64;
+            for number in card.have.iter() {
+                if card.winning.contains(number) {
+                    if points == 0 {
+                        points = 1;
+                    } else {
+                        points = points.checked_mul(2).ok_or(Overflow)?;
+                    }
+                }
+            }
+            total_points = total_points.checked_add(points).ok_or(Overflow)?;
+        }
+
+        Ok(total_points.into())
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::{HashSet, VecDeque};
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, Answer, Overflow, Part, Solution};
+
+pub struct Day04;
+
+/// Solves part 2 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day04>(Part::Two, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day04, R>(Part::Two, reader)
+}
+
+pub struct Card {
+    have: Vec<String>,
+    winning: HashSet<String>,
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut cards = Vec::new();
+
+        for line in lines(input) {
+            let start_i = line
+                .find(":")
+                .ok_or_else(|| line.error_here("colon not found"))?
+                + 1;
+            let Some((winning, have)) = &line[start_i..].split_once('|') else {
+                return Err(line.error(start_i, "| not found after the colon").into());
+            };
+            let winning = winning
+                .split(" ")
+                .filter(|s| !s.is_empty())
+                .map(Into::into)
+                .collect::<HashSet<_>>();
+            let have = have
+                .split(' ')
+                .filter(|s| !s.is_empty())
+                .map(Into::into)
+                .collect::<Vec<_>>();
+            cards.push(Card { have, winning });
+        }
+
+        Ok(cards)
+    }
+
+    fn part2(cards: &Self::Parsed) -> Result<Answer, Error> {
+        let mut total_cards = 0u64;
+
+        let mut copies = VecDeque::<u64>::new();
+
+        for card in cards.iter() {
+            let mut this_cards = 1u64;
+            if let Some(this_copies) = copies.pop_front() {
+                this_cards = this_cards.checked_add(this_copies).ok_or(Overflow)?;
+            }
+            total_cards = total_cards.checked_add(this_cards).ok_or(Overflow)?;
+
+            let mut matches = 0;
+            for number in card.have.iter() {
+                if card.winning.contains(number) {
+                    matches += 1;
+                }
+            }
+            for i in 0..matches {
+                if i < copies.len() {
+                    copies[i] = copies[i].checked_add(this_cards).ok_or(Overflow)?;
+                } else {
+                    copies.push_back(this_cards);
+                }
+            }
+        }
+
+        Ok(total_cards.into())
+    }
+}
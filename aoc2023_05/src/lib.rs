@@ -0,0 +1,175 @@
+use std::cmp::min;
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, Answer, Part, Solution};
+
+pub struct Day05;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day05>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day05, R>(Part::One, reader)
+}
+
+pub struct Almanac {
+    maps: Vec<RangeMap>,
+    seeds: Vec<u64>,
+}
+
+struct RangeMap {
+    entries: Vec<RangeMapEntry>,
+}
+
+struct RangeMapEntry {
+    destination: u64,
+    length: u64,
+    source: u64,
+}
+
+impl RangeMap {
+    fn convert(&self, source: u64) -> u64 {
+        let mut destination = source;
+        for entry in self.entries.iter() {
+            destination = entry.convert(source);
+            if destination != source {
+                break;
+            }
+        }
+        destination
+    }
+
+    fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl RangeMapEntry {
+    fn convert(&self, source: u64) -> u64 {
+        if source >= self.source && source < self.source + self.length {
+            self.destination + (source - self.source)
+        } else {
+            source
+        }
+    }
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut seeds = Vec::<u64>::new();
+        let mut maps = Vec::<RangeMap>::new();
+
+        for line in lines(input) {
+            if line.is_empty() {
+            } else if let Some(seeds_str) = line.strip_prefix("seeds: ") {
+                seeds = seeds_str
+                    .split(" ")
+                    .map(|s| line.parse::<u64>(s))
+                    .collect::<Result<Vec<_>, _>>()?;
+            } else if line.ends_with(" map:") {
+                maps.push(RangeMap::new());
+            } else {
+                let mut numbers = line.split(" ");
+                let destination = numbers
+                    .next()
+                    .ok_or_else(|| line.error_here("missing destination from entry"))?;
+                let destination = line.parse::<u64>(destination)?;
+                let source = numbers
+                    .next()
+                    .ok_or_else(|| line.error_here("missing source from entry"))?;
+                let source = line.parse::<u64>(source)?;
+                let length = numbers
+                    .next()
+                    .ok_or_else(|| line.error_here("missing length from entry"))?;
+                let length = line.parse::<u64>(length)?;
+                // so that converting through the entry can't overflow
+                if destination.checked_add(length).is_none() || source.checked_add(length).is_none()
+                {
+                    return Err(line.error_here("range runs past the largest number").into());
+                }
+                let entry = RangeMapEntry {
+                    destination,
+                    length,
+                    source,
+                };
+                maps.last_mut()
+                    .ok_or_else(|| line.error_here("entry before the first map"))?
+                    .entries
+                    .push(entry);
+            }
+        }
+
+        Ok(Almanac { maps, seeds })
+    }
+
+    fn part1(almanac: &Self::Parsed) -> Result<Answer, Error> {
+        let mut smallest_location = u64::MAX;
+
+        for seed in almanac.seeds.iter() {
+            let mut destination = *seed;
+            for map in almanac.maps.iter() {
+                destination = map.convert(destination);
+            }
+            smallest_location = min(smallest_location, destination);
+        }
+
+        Ok(smallest_location.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seed_to_soil() -> RangeMap {
+        RangeMap {
+            entries: vec![
+                RangeMapEntry {
+                    destination: 50,
+                    length: 2,
+                    source: 98,
+                },
+                RangeMapEntry {
+                    destination: 52,
+                    length: 48,
+                    source: 50,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn convert_mapped() {
+        let map = seed_to_soil();
+        assert_eq!(map.convert(79), 81);
+        assert_eq!(map.convert(55), 57);
+        assert_eq!(map.convert(98), 50);
+        assert_eq!(map.convert(99), 51);
+    }
+
+    #[test]
+    fn convert_range_ends() {
+        let map = seed_to_soil();
+        assert_eq!(map.convert(50), 52);
+        assert_eq!(map.convert(97), 99);
+    }
+
+    #[test]
+    fn convert_unmapped() {
+        let map = seed_to_soil();
+        assert_eq!(map.convert(0), 0);
+        assert_eq!(map.convert(13), 13);
+        assert_eq!(map.convert(49), 49);
+        assert_eq!(map.convert(100), 100);
+    }
+}
@@ -0,0 +1,249 @@
+use std::cmp::min;
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, Answer, Part, Solution};
+
+pub struct Day05;
+
+/// Solves part 2 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day05>(Part::Two, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day05, R>(Part::Two, reader)
+}
+
+pub struct Almanac {
+    maps: Vec<RangeMap>,
+    seed_ranges: Vec<Range>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Range {
+    length: u64,
+    start: u64,
+}
+
+struct RangeMap {
+    entries: Vec<RangeMapEntry>,
+}
+
+struct RangeMapEntry {
+    destination: u64,
+    length: u64,
+    source: u64,
+}
+
+impl Range {
+    fn until_max(start: u64) -> Self {
+        Self {
+            length: u64::MAX - start,
+            start,
+        }
+    }
+}
+
+impl RangeMap {
+    fn convert(&self, source: u64) -> Range {
+        let initial_destination = Range::until_max(source);
+        let mut destination = initial_destination;
+        for entry in self.entries.iter() {
+            destination = entry.convert(source);
+            if destination != initial_destination {
+                break;
+            }
+            if entry.source > source {
+                destination.length = entry.source - source;
+                break;
+            }
+        }
+        destination
+    }
+
+    fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    fn prepare(&mut self) {
+        self.entries.sort_by_key(|entry| entry.source);
+    }
+}
+
+impl RangeMapEntry {
+    fn convert(&self, source: u64) -> Range {
+        if source >= self.source && source < self.source + self.length {
+            Range {
+                length: self.length - (source - self.source),
+                start: self.destination + (source - self.source),
+            }
+        } else {
+            Range::until_max(source)
+        }
+    }
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut seed_ranges = Vec::<Range>::new();
+        let mut maps = Vec::<RangeMap>::new();
+
+        for line in lines(input) {
+            if line.is_empty() {
+            } else if let Some(seeds_str) = line.strip_prefix("seeds: ") {
+                let mut numbers = seeds_str.split(" ").map(|s| line.parse::<u64>(s));
+                while let Some(start) = numbers.next() {
+                    let start = start?;
+                    let length = numbers
+                        .next()
+                        .ok_or_else(|| line.error_here("seed range without a length"))??;
+                    if start.checked_add(length).is_none() {
+                        return Err(line.error_here("range runs past the largest number").into());
+                    }
+                    let range = Range { length, start };
+                    seed_ranges.push(range);
+                }
+            } else if line.ends_with(" map:") {
+                maps.push(RangeMap::new());
+            } else {
+                let mut numbers = line.split(" ");
+                let destination = numbers
+                    .next()
+                    .ok_or_else(|| line.error_here("missing destination from entry"))?;
+                let destination = line.parse::<u64>(destination)?;
+                let source = numbers
+                    .next()
+                    .ok_or_else(|| line.error_here("missing source from entry"))?;
+                let source = line.parse::<u64>(source)?;
+                let length = numbers
+                    .next()
+                    .ok_or_else(|| line.error_here("missing length from entry"))?;
+                let length = line.parse::<u64>(length)?;
+                // so that converting through the entry can't overflow
+                if destination.checked_add(length).is_none() || source.checked_add(length).is_none()
+                {
+                    return Err(line.error_here("range runs past the largest number").into());
+                }
+                let entry = RangeMapEntry {
+                    destination,
+                    length,
+                    source,
+                };
+                maps.last_mut()
+                    .ok_or_else(|| line.error_here("entry before the first map"))?
+                    .entries
+                    .push(entry);
+            }
+        }
+
+        for map in maps.iter_mut() {
+            map.prepare();
+        }
+
+        Ok(Almanac { maps, seed_ranges })
+    }
+
+    fn part2(almanac: &Self::Parsed) -> Result<Answer, Error> {
+        let mut smallest_location = u64::MAX;
+
+        for seed_range in almanac.seed_ranges.iter() {
+            let mut seed = seed_range.start;
+            while seed < seed_range.start + seed_range.length {
+                let mut destination = Range::until_max(seed);
+                for map in almanac.maps.iter() {
+                    let this_destination = map.convert(destination.start);
+                    destination = Range {
+                        length: min(this_destination.length, destination.length),
+                        start: this_destination.start,
+                    };
+                }
+                smallest_location = min(smallest_location, destination.start);
+                seed += destination.length;
+            }
+        }
+
+        Ok(smallest_location.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn soil_to_fertilizer() -> RangeMap {
+        let mut map = RangeMap {
+            entries: vec![
+                RangeMapEntry {
+                    destination: 0,
+                    length: 37,
+                    source: 15,
+                },
+                RangeMapEntry {
+                    destination: 37,
+                    length: 2,
+                    source: 52,
+                },
+                RangeMapEntry {
+                    destination: 39,
+                    length: 15,
+                    source: 0,
+                },
+            ],
+        };
+        map.prepare();
+        map
+    }
+
+    #[test]
+    fn convert_mapped() {
+        let map = soil_to_fertilizer();
+        assert_eq!(
+            map.convert(0),
+            Range {
+                length: 15,
+                start: 39
+            }
+        );
+        assert_eq!(
+            map.convert(20),
+            Range {
+                length: 32,
+                start: 5
+            }
+        );
+        assert_eq!(
+            map.convert(53),
+            Range {
+                length: 1,
+                start: 38
+            }
+        );
+    }
+
+    #[test]
+    fn convert_unmapped_before_entries() {
+        let mut map = soil_to_fertilizer();
+        map.entries.retain(|entry| entry.source != 0);
+        assert_eq!(
+            map.convert(3),
+            Range {
+                length: 12,
+                start: 3
+            }
+        );
+    }
+
+    #[test]
+    fn convert_unmapped_after_entries() {
+        let map = soil_to_fertilizer();
+        assert_eq!(map.convert(54), Range::until_max(54));
+    }
+}
@@ -0,0 +1,85 @@
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, Answer, InputError, Part, Solution};
+use log::debug;
+use num_bigint::BigUint;
+
+pub struct Day06;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day06>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day06, R>(Part::One, reader)
+}
+
+pub struct Races {
+    distances: Vec<u64>,
+    times: Vec<u64>,
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed = Races;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut times = Vec::<u64>::new();
+        let mut distances = Vec::<u64>::new();
+
+        for line in lines(input) {
+            let (_, numbers) = line
+                .split_once(':')
+                .ok_or_else(|| line.error_here("missing colon"))?;
+            let numbers = numbers
+                .split(" ")
+                .filter(|s| !s.is_empty())
+                .map(|s| line.parse::<u64>(s))
+                .collect::<Result<Vec<_>, _>>()?;
+            if times.is_empty() {
+                times = numbers;
+            } else if distances.is_empty() {
+                distances = numbers;
+            }
+        }
+
+        if times.len() != distances.len() {
+            return Err(InputError::new(format!(
+                "{} times but {} distances",
+                times.len(),
+                distances.len()
+            ))
+            .into());
+        }
+
+        Ok(Races { distances, times })
+    }
+
+    fn part1(races: &Self::Parsed) -> Result<Answer, Error> {
+        // a product over every race soon outgrows any fixed size integer
+        let mut win_combos = BigUint::from(1u8);
+
+        for i in 0..races.times.len() {
+            let time = races.times[i] as f64;
+            let distance = races.distances[i] as f64;
+            // time_held * (time_race - time_held) > distance
+            // time_held * time_race - time_held**2 > distance
+            // -time_held**2 + time_race*time_held - distance > 0
+            // a = -1, b = time_race, c = -distance
+            let mut x = [
+                ((-time - (time * time - -4. * (-distance)).sqrt()) / -2.),
+                ((-time + (time * time - -4. * (-distance)).sqrt()) / -2.),
+            ];
+            x.sort_by(f64::total_cmp);
+            let combos = ((x[1] - 0.0000001).floor() - (x[0] + 0.000001).ceil() + 1.) as u64;
+            debug!("{} {} => {} {} {}", time, distance, x[0], x[1], combos);
+            win_combos *= combos;
+        }
+
+        Ok(win_combos.into())
+    }
+}
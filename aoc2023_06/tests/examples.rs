@@ -0,0 +1,15 @@
+#[test]
+fn example() {
+    let answer = aoc2023_06::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "288");
+}
+
+#[test]
+fn product_past_u128() {
+    // 30 races each won 59 ways
+    let times = " 60".repeat(30);
+    let distances = " 1".repeat(30);
+    let input = format!("Time:{}\nDistance:{}\n", times, distances);
+    let answer = aoc2023_06::solve(&input).unwrap();
+    assert_eq!(answer, num_bigint::BigUint::from(59u8).pow(30).to_string());
+}
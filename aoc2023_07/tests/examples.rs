@@ -0,0 +1,16 @@
+use aoc2023_common::InputError;
+
+#[test]
+fn example() {
+    let answer = aoc2023_07::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "6440");
+}
+
+#[test]
+fn malformed_input() {
+    let error = aoc2023_07::solve("32T3K 765\nT5X5J 684\n").unwrap_err();
+    let error = error.downcast_ref::<InputError>().unwrap();
+    assert_eq!(error.day(), Some(7));
+    assert_eq!((error.line(), error.column()), (Some(2), Some(3)));
+    assert_eq!(error.message(), "invalid card 'X'");
+}
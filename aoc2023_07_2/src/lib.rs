@@ -0,0 +1,276 @@
+use std::cmp::max;
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, Answer, Overflow, Part, Solution};
+use log::trace;
+
+pub struct Day07;
+
+/// Solves part 2 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day07>(Part::Two, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day07, R>(Part::Two, reader)
+}
+
+pub struct Bid {
+    bid: u64,
+    cards: String,
+    /// The strength of each card, in order.
+    values: [u8; 5],
+}
+
+struct Hand {
+    cards: [u8; 5],
+    bid: u64,
+    score: u8,
+}
+
+fn best_wild_combo(cards: &[u8]) -> u8 {
+    let mut num_wildcards = 0;
+    for card in cards {
+        if *card == b'J' {
+            num_wildcards += 1;
+        }
+    }
+
+    // check what is available without using wildcards
+    let combo = calculate_combo(cards);
+
+    match num_wildcards {
+        5 => 6,
+        4 => 6,
+        3 => {
+            match combo {
+                1 => 6, // 2 of a kind => 5 of a kind
+                0 => 5, // high card => 4 of a kind
+                _ => panic!("impossible 3 wildcard"),
+            }
+        }
+        2 => {
+            match combo {
+                3 => 6, // 3 of a kind => 5 of a kind
+                1 => 5, // one pair => 4 of a kind
+                0 => 3, // high card => 3 of a kind
+                _ => panic!("impossible 2 wildcard"),
+            }
+        }
+        1 => {
+            match combo {
+                5 => 6, // 4 of a kind => 5 of a kind
+                3 => 5, // 3 of a kind => 4 of a kind
+                2 => 4, // two pair => full house
+                1 => 3, // one pair => 3 of a kind
+                0 => 1, // high card => one pair
+                _ => panic!("impossible 1 wildcard"),
+            }
+        }
+        0 => combo,
+        _ => panic!("impossible num_wildcards"),
+    }
+}
+
+fn convert_card(text: u8) -> Option<u8> {
+    let value = match text {
+        b'J' => 1,
+        b'2' => 2,
+        b'3' => 3,
+        b'4' => 4,
+        b'5' => 5,
+        b'6' => 6,
+        b'7' => 7,
+        b'8' => 8,
+        b'9' => 9,
+        b'T' => 10,
+        b'Q' => 12,
+        b'K' => 13,
+        b'A' => 14,
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn calculate_combo(cards: &[u8]) -> u8 {
+    let mut counters = [0u8; 256];
+    let mut three_card = 0u8;
+    let mut two_card_1 = 0u8;
+    let mut two_card_2 = 0u8;
+    let mut combo: u8 = 0;
+
+    for card in cards {
+        let card = *card;
+
+        if card == b'J' {
+            continue;
+        }
+
+        counters[card as usize] += 1;
+        let counter = counters[card as usize];
+        if counter == 2 {
+            if two_card_1 != 0 {
+                two_card_2 = card;
+            } else {
+                two_card_1 = card;
+            }
+
+            if three_card != 0 {
+                combo = max(combo, 4);
+            } else if two_card_2 != 0 {
+                combo = max(combo, 2);
+            } else {
+                combo = max(combo, 1);
+            }
+        } else if counter == 3 {
+            if two_card_1 == card {
+                two_card_1 = two_card_2;
+                two_card_2 = 0;
+            } else if two_card_2 == card {
+                two_card_2 = 0;
+            }
+            three_card = card;
+            if two_card_1 != 0 {
+                combo = max(combo, 4);
+            } else {
+                combo = max(combo, 3);
+            }
+        } else if counter == 4 {
+            three_card = 0;
+            combo = max(combo, 5);
+        } else if counter == 5 {
+            combo = max(combo, 6);
+        }
+    }
+
+    combo
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Parsed = Vec<Bid>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut bids = Vec::new();
+
+        for line in lines(input) {
+            let (cards, bid_str) = line
+                .split_once(" ")
+                .ok_or_else(|| line.error_here("expected cards and a bid"))?;
+
+            let bid = line.parse::<u64>(bid_str)?;
+
+            let mut values = [0; 5];
+            if cards.len() != values.len() {
+                return Err(line
+                    .error_at(cards, format!("hand has {} cards, expected 5", cards.len()))
+                    .into());
+            }
+            for (i, (value, card)) in values.iter_mut().zip(cards.bytes()).enumerate() {
+                *value = convert_card(card)
+                    .ok_or_else(|| line.error(i, format!("invalid card {:?}", card as char)))?;
+            }
+
+            bids.push(Bid {
+                bid,
+                cards: cards.to_owned(),
+                values,
+            });
+        }
+
+        Ok(bids)
+    }
+
+    fn part2(bids: &Self::Parsed) -> Result<Answer, Error> {
+        let mut hands = Vec::<Hand>::new();
+
+        for Bid { bid, cards, values } in bids.iter() {
+            let bid = *bid;
+
+            let combo = best_wild_combo(cards.as_bytes());
+
+            trace!("{} {} {}", cards, bid, combo);
+
+            let hand = Hand {
+                bid,
+                cards: *values,
+                score: combo,
+            };
+            hands.push(hand);
+        }
+
+        hands.sort_by_key(|hand| (hand.score, hand.cards));
+
+        let mut winnings = 0u64;
+
+        for (rank, hand) in (1..).zip(hands.iter()) {
+            let winning = hand.bid.checked_mul(rank).ok_or(Overflow)?;
+            trace!(
+                "{} {} {:?} {} {}",
+                hand.bid,
+                hand.score,
+                hand.cards,
+                rank,
+                winning
+            );
+            winnings = winnings.checked_add(winning).ok_or(Overflow)?;
+        }
+
+        Ok(winnings.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculate_combo_kinds() {
+        assert_eq!(calculate_combo(b"23456"), 0);
+        assert_eq!(calculate_combo(b"A23A4"), 1);
+        assert_eq!(calculate_combo(b"23432"), 2);
+        assert_eq!(calculate_combo(b"TTT98"), 3);
+        assert_eq!(calculate_combo(b"23332"), 4);
+        assert_eq!(calculate_combo(b"AA8AA"), 5);
+        assert_eq!(calculate_combo(b"AAAAA"), 6);
+    }
+
+    #[test]
+    fn calculate_combo_full_house_order() {
+        // the pair and the triple can complete in either order
+        assert_eq!(calculate_combo(b"22333"), 4);
+        assert_eq!(calculate_combo(b"33322"), 4);
+        assert_eq!(calculate_combo(b"32322"), 4);
+        assert_eq!(calculate_combo(b"23233"), 4);
+    }
+
+    #[test]
+    fn calculate_combo_pair_grows() {
+        // a pair that becomes a triple or four of a kind is no longer a pair
+        assert_eq!(calculate_combo(b"22234"), 3);
+        assert_eq!(calculate_combo(b"22232"), 5);
+        assert_eq!(calculate_combo(b"23222"), 5);
+    }
+
+    #[test]
+    fn calculate_combo_ignores_jokers() {
+        assert_eq!(calculate_combo(b"JJ234"), 0);
+        assert_eq!(calculate_combo(b"KTJJT"), 1);
+        assert_eq!(calculate_combo(b"JJJJJ"), 0);
+    }
+
+    #[test]
+    fn best_wild_combo_upgrades() {
+        assert_eq!(best_wild_combo(b"32T3K"), 1);
+        assert_eq!(best_wild_combo(b"2345J"), 1);
+        assert_eq!(best_wild_combo(b"2233J"), 4);
+        assert_eq!(best_wild_combo(b"T55J5"), 5);
+        assert_eq!(best_wild_combo(b"KTJJT"), 5);
+        assert_eq!(best_wild_combo(b"QQQJA"), 5);
+        assert_eq!(best_wild_combo(b"JJJJ2"), 6);
+        assert_eq!(best_wild_combo(b"JJJJJ"), 6);
+    }
+}
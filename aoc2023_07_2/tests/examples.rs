@@ -0,0 +1,5 @@
+#[test]
+fn example() {
+    let answer = aoc2023_07_2::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "5905");
+}
@@ -0,0 +1,98 @@
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, Answer, InputError, Part, Progress, Solution};
+
+pub struct Day08;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day08>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day08, R>(Part::One, reader)
+}
+
+pub struct Network {
+    instructions: Vec<u8>,
+    nodes: HashMap<String, (String, String)>,
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed = Network;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut instructions = Vec::<u8>::new();
+        let mut nodes = HashMap::<String, (String, String)>::new();
+
+        for line in lines(input) {
+            if line.is_empty() {
+                continue;
+            }
+
+            if instructions.is_empty() {
+                if let Some(i) = line.bytes().position(|b| b != b'L' && b != b'R') {
+                    return Err(line.error(i, "instructions must be L or R").into());
+                }
+                instructions.extend(line.as_bytes());
+                continue;
+            }
+
+            let mut parts = line
+                .split(|c| " =(,)".contains(c))
+                .filter(|s| !s.is_empty());
+            let (Some(name), Some(left), Some(right)) = (parts.next(), parts.next(), parts.next())
+            else {
+                return Err(line
+                    .error_here("expected a node like AAA = (BBB, CCC)")
+                    .into());
+            };
+            let (name, left, right) = (name.to_owned(), left.to_owned(), right.to_owned());
+
+            nodes.insert(name, (left, right));
+        }
+
+        if instructions.is_empty() {
+            return Err(InputError::new("missing instructions").into());
+        }
+
+        Ok(Network {
+            instructions,
+            nodes,
+        })
+    }
+
+    fn part1(network: &Self::Parsed) -> Result<Answer, Error> {
+        let progress = Progress::new(module_path!(), None);
+        let mut seen = HashSet::<(&str, usize)>::new();
+        let mut steps = 0;
+        let mut pos = "AAA";
+
+        for (i, instruction) in network.instructions.iter().enumerate().cycle() {
+            if pos == "ZZZ" {
+                break;
+            }
+            if !seen.insert((pos, i)) {
+                return Err(InputError::new("the walk from AAA never reaches ZZZ").into());
+            }
+            progress.tick()?;
+            let node = network
+                .nodes
+                .get(pos)
+                .ok_or_else(|| InputError::new(format!("unknown node {:?}", pos)))?;
+            pos = match instruction {
+                b'L' => &node.0,
+                b'R' => &node.1,
+                _ => unreachable!("instructions are checked while parsing"),
+            };
+            steps += 1;
+        }
+
+        Ok(steps.into())
+    }
+}
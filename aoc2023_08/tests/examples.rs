@@ -0,0 +1,20 @@
+use aoc2023_common::InputError;
+
+#[test]
+fn example1() {
+    let answer = aoc2023_08::solve(include_str!("examples/example1.txt")).unwrap();
+    assert_eq!(answer, "2");
+}
+
+#[test]
+fn example2() {
+    let answer = aoc2023_08::solve(include_str!("examples/example2.txt")).unwrap();
+    assert_eq!(answer, "6");
+}
+
+#[test]
+fn walk_that_never_reaches_zzz() {
+    let error = aoc2023_08::solve("L\n\nAAA = (AAA, AAA)\n").unwrap_err();
+    let error = error.downcast_ref::<InputError>().unwrap();
+    assert_eq!(error.message(), "the walk from AAA never reaches ZZZ");
+}
@@ -0,0 +1,161 @@
+use std::cmp::min;
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, Answer, InputError, Overflow, Part, Progress, Solution};
+use itertools::Itertools;
+use log::{debug, trace};
+use num::integer::gcd;
+
+pub struct Day08;
+
+/// Solves part 2 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day08>(Part::Two, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day08, R>(Part::Two, reader)
+}
+
+pub struct Network {
+    instructions: Vec<u8>,
+    nodes: HashMap<String, (String, String)>,
+}
+
+fn list_solutions(
+    nodes: &HashMap<String, (String, String)>,
+    instructions: &[u8],
+    start_pos: &str,
+) -> Result<Vec<usize>, Error> {
+    debug!("list_solutions({})", start_pos);
+    let mut pos = start_pos;
+    let mut seen = HashSet::<(&str, usize)>::new();
+    let mut solutions = Vec::<usize>::new();
+    let mut steps = 0;
+    'outer: loop {
+        for (i, instruction) in instructions.iter().enumerate() {
+            if !seen.insert((pos, i)) {
+                debug!("cycle at {} {}", pos, i);
+                break 'outer;
+            }
+
+            let node = nodes
+                .get(pos)
+                .ok_or_else(|| InputError::new(format!("unknown node {:?}", pos)))?;
+            pos = match instruction {
+                b'L' => node.0.as_str(),
+                b'R' => node.1.as_str(),
+                _ => unreachable!("instructions are checked while parsing"),
+            };
+            trace!("now at {}", pos);
+            steps += 1;
+            if pos.ends_with('Z') {
+                debug!("solution at {}, {} steps", pos, steps);
+                solutions.push(steps);
+            }
+        }
+    }
+    debug!("list_solutions({}) end", start_pos);
+    if solutions.is_empty() {
+        return Err(InputError::new(format!("ghost {} never reaches a Z node", start_pos)).into());
+    }
+    Ok(solutions)
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed = Network;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut instructions = Vec::<u8>::new();
+        let mut nodes = HashMap::<String, (String, String)>::new();
+
+        for line in lines(input) {
+            if line.is_empty() {
+                continue;
+            }
+
+            if instructions.is_empty() {
+                if let Some(i) = line.bytes().position(|b| b != b'L' && b != b'R') {
+                    return Err(line.error(i, "instructions must be L or R").into());
+                }
+                instructions.extend(line.as_bytes());
+                continue;
+            }
+
+            let mut parts = line
+                .split(|c| " =(,)".contains(c))
+                .filter(|s| !s.is_empty());
+            let (Some(name), Some(left), Some(right)) = (parts.next(), parts.next(), parts.next())
+            else {
+                return Err(line
+                    .error_here("expected a node like AAA = (BBB, CCC)")
+                    .into());
+            };
+            let (name, left, right) = (name.to_owned(), left.to_owned(), right.to_owned());
+
+            nodes.insert(name, (left, right));
+        }
+
+        if instructions.is_empty() {
+            return Err(InputError::new("missing instructions").into());
+        }
+
+        Ok(Network {
+            instructions,
+            nodes,
+        })
+    }
+
+    fn part2(network: &Self::Parsed) -> Result<Answer, Error> {
+        let poses = network
+            .nodes
+            .keys()
+            .filter(|k| k.ends_with('A'))
+            .map(|k| k.as_str());
+
+        let solutionss = poses
+            .map(|pos| list_solutions(&network.nodes, &network.instructions, pos))
+            .collect::<Result<Vec<_>, _>>()?;
+        if solutionss.is_empty() {
+            return Err(InputError::new("no nodes end in A").into());
+        }
+
+        debug!("solutionss len {}", solutionss.len());
+        for solutions in solutionss.iter() {
+            debug!("solutions len {}", solutions.len());
+        }
+
+        let mut best = usize::MAX;
+
+        let progress = Progress::new(module_path!(), None);
+        for solutions in solutionss
+            .iter()
+            .map(|solutions| solutions.iter())
+            .multi_cartesian_product()
+        {
+            trace!("loop");
+            progress.tick()?;
+            let mut lcm_value = 1;
+            for solution in solutions {
+                lcm_value = checked_lcm(lcm_value, *solution)?;
+            }
+            best = min(best, lcm_value);
+        }
+
+        Ok(best.into())
+    }
+}
+
+// the least common multiple, which for cycle lengths of a few thousand steps each quickly
+// outgrows a usize
+fn checked_lcm(a: usize, b: usize) -> Result<usize, Overflow> {
+    if a == 0 || b == 0 {
+        return Ok(0);
+    }
+    (a / gcd(a, b)).checked_mul(b).ok_or(Overflow)
+}
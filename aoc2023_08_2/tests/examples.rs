@@ -0,0 +1,18 @@
+#[test]
+fn example() {
+    let answer = aoc2023_08_2::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "6");
+}
+
+#[test]
+fn ghost_never_reaches_z() {
+    let input = "L\n\n11A = (11Z, XXX)\n11Z = (11A, XXX)\n22A = (22A, XXX)\n";
+    let error = aoc2023_08_2::solve(input).unwrap_err();
+    assert_eq!(error.to_string(), "day 8: ghost 22A never reaches a Z node");
+}
+
+#[test]
+fn no_start_nodes() {
+    let error = aoc2023_08_2::solve("L\n\n11Z = (11Z, 11Z)\n").unwrap_err();
+    assert_eq!(error.to_string(), "day 8: no nodes end in A");
+}
@@ -0,0 +1,70 @@
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, Answer, Overflow, Part, Solution};
+
+pub struct Day09;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day09>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day09, R>(Part::One, reader)
+}
+
+fn differentiate(numbers: &[i64]) -> Result<Vec<i64>, Overflow> {
+    let mut ret = Vec::<i64>::new();
+
+    for i in 1..numbers.len() {
+        ret.push(numbers[i].checked_sub(numbers[i - 1]).ok_or(Overflow)?);
+    }
+
+    Ok(ret)
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut histories = Vec::new();
+
+        for line in lines(input) {
+            let numbers = line
+                .split(' ')
+                .map(|s| line.parse::<i64>(s))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            histories.push(numbers);
+        }
+
+        Ok(histories)
+    }
+
+    fn part1(histories: &Self::Parsed) -> Result<Answer, Error> {
+        let mut total = 0i64;
+
+        for numbers in histories.iter() {
+            let mut differentiated = numbers.clone();
+            let mut extrapolated = *numbers.last().unwrap();
+
+            loop {
+                differentiated = differentiate(&differentiated)?;
+                if differentiated.iter().all(|n| *n == 0) {
+                    break;
+                }
+                extrapolated = extrapolated
+                    .checked_add(*differentiated.last().unwrap())
+                    .ok_or(Overflow)?;
+            }
+
+            total = total.checked_add(extrapolated).ok_or(Overflow)?;
+        }
+
+        Ok(total.into())
+    }
+}
@@ -0,0 +1,99 @@
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{Answer, Direction, Grid, InputError, Part, Solution};
+use log::{debug, trace};
+
+pub struct Day10;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day10>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day10, R>(Part::One, reader)
+}
+
+const PIPES: [(u8, [Direction; 2]); 6] = [
+    (b'|', [Direction::Up, Direction::Down]),
+    (b'-', [Direction::Left, Direction::Right]),
+    (b'L', [Direction::Up, Direction::Right]),
+    (b'J', [Direction::Up, Direction::Left]),
+    (b'7', [Direction::Down, Direction::Left]),
+    (b'F', [Direction::Down, Direction::Right]),
+];
+
+// the direction to leave `tile` by after entering it heading `direction`, if the pipe connects
+fn follow(tile: u8, direction: Direction) -> Option<Direction> {
+    let (_, [a, b]) = PIPES.iter().find(|(t, _)| *t == tile)?;
+    let from = direction.reverse();
+    if *a == from {
+        Some(*b)
+    } else if *b == from {
+        Some(*a)
+    } else {
+        None
+    }
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Grid::parse_with(input, |b| {
+            if b"|-LJ7F.S".contains(&b) {
+                Ok(b)
+            } else {
+                Err(Error::msg(format!("invalid tile {:?}", b as char)))
+            }
+        })
+    }
+
+    fn part1(tiles: &Self::Parsed) -> Result<Answer, Error> {
+        let start_pos = tiles
+            .position(|t| *t == b'S')
+            .ok_or_else(|| InputError::new("start tile missing"))?;
+
+        debug!("start_pos {:?}", start_pos);
+
+        // https://stackoverflow.com/questions/27393166/how-do-i-initialize-an-array-of-vectors
+        //let mut distances = [
+        //    vec![0u64; tiles.len()],
+        //    vec![0u64; tiles.len()],
+        //    vec![0u64; tiles.len()],
+        //    vec![0u64; tiles.len()],
+        //];
+        //let mut proper_loop = [false; 4];
+
+        for first_direction in Direction::ALL {
+            debug!("first_direction {:?}", first_direction);
+
+            let mut pos = tiles.step(start_pos, first_direction);
+            let mut distance = 1;
+            let mut direction = first_direction;
+
+            while let Some(some_pos) = pos {
+                //distances[first_direction][some_pos] = distance;
+                distance += 1;
+
+                let tile = tiles[some_pos];
+                trace!("tile {} distance {}", tile as char, distance);
+                if tile == b'S' {
+                    //proper_loop[first_direction] = true;
+                    return Ok((distance / 2).into());
+                }
+                let Some(next_direction) = follow(tile, direction) else {
+                    break;
+                };
+                direction = next_direction;
+                pos = tiles.step(some_pos, direction);
+            }
+        }
+
+        Err(InputError::new("no loop found").into())
+    }
+}
@@ -0,0 +1,22 @@
+use aoc2023_common::InputError;
+
+#[test]
+fn example1() {
+    let answer = aoc2023_10::solve(include_str!("examples/example1.txt")).unwrap();
+    assert_eq!(answer, "4");
+}
+
+#[test]
+fn example2() {
+    let answer = aoc2023_10::solve(include_str!("examples/example2.txt")).unwrap();
+    assert_eq!(answer, "8");
+}
+
+#[test]
+fn malformed_input() {
+    let error = aoc2023_10::solve("...\n.S-\n...\n").unwrap_err();
+    let error = error.downcast_ref::<InputError>().unwrap();
+    assert_eq!(error.day(), Some(10));
+    assert_eq!(error.line(), None);
+    assert_eq!(error.message(), "no loop found");
+}
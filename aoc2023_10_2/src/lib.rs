@@ -0,0 +1,199 @@
+use std::cmp::min;
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{Answer, Direction, Grid, InputError, Part, Solution, Strategy};
+use log::{debug, trace};
+
+pub struct Day10;
+
+/// Solves part 2 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day10>(Part::Two, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day10, R>(Part::Two, reader)
+}
+
+const PIPES: [(u8, [Direction; 2]); 6] = [
+    (b'|', [Direction::Up, Direction::Down]),
+    (b'-', [Direction::Left, Direction::Right]),
+    (b'L', [Direction::Up, Direction::Right]),
+    (b'J', [Direction::Up, Direction::Left]),
+    (b'7', [Direction::Down, Direction::Left]),
+    (b'F', [Direction::Down, Direction::Right]),
+];
+
+// the direction to leave `tile` by after entering it heading `direction`, if the pipe connects
+fn follow(tile: u8, direction: Direction) -> Option<Direction> {
+    let (_, [a, b]) = PIPES.iter().find(|(t, _)| *t == tile)?;
+    let from = direction.reverse();
+    if *a == from {
+        Some(*b)
+    } else if *b == from {
+        Some(*a)
+    } else {
+        None
+    }
+}
+
+fn flood_fill(tiles: &Grid<u8>, gaps: &mut Grid<u8>) {
+    let mut candidates = vec![(0, 0)];
+
+    while let Some((x, y)) = candidates.pop() {
+        trace!("flood_fill ({}, {})", x, y);
+        if gaps[(x, y)] != b'.' {
+            continue;
+        }
+        gaps[(x, y)] = b'O';
+
+        let tile = |x: usize, y: usize| tiles.get(x, y).copied().unwrap_or(b'.');
+
+        for (next_x, next_y) in gaps.neighbours4(x, y) {
+            let blocked = if next_y == y {
+                // left/right crosses the top of the tile below, blocked by a vertical at the top
+                b"|LJ".contains(&tile(min(x, next_x), y))
+            } else {
+                // up/down crosses the left of the tile to the right, blocked by a horizontal at
+                // the left
+                b"-J7".contains(&tile(x, min(y, next_y)))
+            };
+            if !blocked {
+                candidates.push((next_x, next_y));
+            }
+        }
+    }
+}
+
+// the tiles of the loop through the start tile in the order they are walked, ending with the
+// start tile itself, and the pipe the start tile must be for the loop to connect
+fn find_loop(tiles: &Grid<u8>) -> Result<(Vec<(usize, usize)>, u8), Error> {
+    let start_pos = tiles
+        .position(|t| *t == b'S')
+        .ok_or_else(|| InputError::new("start tile missing"))?;
+
+    debug!("start_pos {:?}", start_pos);
+
+    for first_direction in Direction::ALL {
+        debug!("first_direction {:?}", first_direction);
+
+        let mut pos = tiles.step(start_pos, first_direction);
+        let mut path = Vec::new();
+        let mut direction = first_direction;
+
+        while let Some(some_pos) = pos {
+            path.push(some_pos);
+
+            let tile = tiles[some_pos];
+            trace!("tile {} distance {}", tile as char, path.len());
+            if tile == b'S' {
+                let connections = [first_direction, direction.reverse()];
+                let (pipe, _) = PIPES
+                    .iter()
+                    .find(|(_, c)| connections.iter().all(|d| c.contains(d)))
+                    .ok_or_else(|| InputError::new("start tile can't connect the loop"))?;
+                return Ok((path, *pipe));
+            }
+            let Some(next_direction) = follow(tile, direction) else {
+                break;
+            };
+            direction = next_direction;
+            pos = tiles.step(some_pos, direction);
+        }
+    }
+
+    Err(InputError::new("no loop found").into())
+}
+
+// floods the gaps between pipes from outside the loop and counts the tiles left unreached
+fn count_enclosed_by_flood_fill(tiles: &Grid<u8>) -> Result<Answer, Error> {
+    let (path, start_tile) = find_loop(tiles)?;
+
+    // copy a map with only the loop
+    let mut loop_tiles = Grid::new(tiles.width(), tiles.height(), b'.');
+    for pos in path.iter().copied() {
+        loop_tiles[pos] = tiles[pos];
+    }
+    if let Some(start_pos) = path.last() {
+        loop_tiles[*start_pos] = start_tile;
+    }
+
+    debug!("{}", loop_tiles);
+
+    // make a map of "gaps" between the pipes. gap (0, 0) is on the top left corner of pipe (0, 0)
+    // and (width, height) is on the bottom right corner of pipe tile (width - 1, height - 1)
+
+    let mut gaps = Grid::new(tiles.width() + 1, tiles.height() + 1, b'.');
+
+    // flood fill from (0, 0)
+    flood_fill(&loop_tiles, &mut gaps);
+
+    debug!("{}", gaps);
+
+    // count tiles that are fully surrounded by . gaps
+    let mut surrounded = 0;
+    for (x, y) in tiles.positions() {
+        if gaps[(x, y)] == b'.'
+            && gaps[(x + 1, y)] == b'.'
+            && gaps[(x, y + 1)] == b'.'
+            && gaps[(x + 1, y + 1)] == b'.'
+        {
+            surrounded += 1;
+        }
+    }
+
+    Ok(surrounded.into())
+}
+
+// the loop's area by the shoelace formula, through the centres of its tiles, then the tiles
+// strictly inside it by Pick's theorem: area = inside + boundary / 2 - 1
+fn count_enclosed_by_shoelace(tiles: &Grid<u8>) -> Result<Answer, Error> {
+    let (path, _) = find_loop(tiles)?;
+
+    let mut twice_area = 0i64;
+    for (i, (x, y)) in path.iter().enumerate() {
+        let (next_x, next_y) = path[(i + 1) % path.len()];
+        twice_area += *x as i64 * next_y as i64 - next_x as i64 * *y as i64;
+    }
+
+    let inside = (twice_area.abs() - path.len() as i64) / 2 + 1;
+    Ok(inside.into())
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Grid::parse_with(input, |b| {
+            if b"|-LJ7F.S".contains(&b) {
+                Ok(b)
+            } else {
+                Err(Error::msg(format!("invalid tile {:?}", b as char)))
+            }
+        })
+    }
+
+    fn part2(tiles: &Self::Parsed) -> Result<Answer, Error> {
+        count_enclosed_by_flood_fill(tiles)
+    }
+
+    fn strategies(part: Part) -> Vec<Strategy<Self::Parsed>> {
+        match part {
+            Part::One => Vec::new(),
+            Part::Two => vec![
+                Strategy {
+                    name: "flood fill",
+                    solve: count_enclosed_by_flood_fill,
+                },
+                Strategy {
+                    name: "shoelace",
+                    solve: count_enclosed_by_shoelace,
+                },
+            ],
+        }
+    }
+}
@@ -0,0 +1,33 @@
+#[test]
+fn example1() {
+    let answer = aoc2023_10_2::solve(include_str!("examples/example1.txt")).unwrap();
+    assert_eq!(answer, "4");
+}
+
+#[test]
+fn example2() {
+    let answer = aoc2023_10_2::solve(include_str!("examples/example2.txt")).unwrap();
+    assert_eq!(answer, "8");
+}
+
+#[test]
+fn example3() {
+    let answer = aoc2023_10_2::solve(include_str!("examples/example3.txt")).unwrap();
+    assert_eq!(answer, "10");
+}
+
+#[test]
+fn strategies_agree() {
+    use aoc2023_10_2::Day10;
+    use aoc2023_common::{cross_check, Part};
+
+    for (input, expected) in [
+        (include_str!("examples/example1.txt"), "4"),
+        (include_str!("examples/example2.txt"), "8"),
+        (include_str!("examples/example3.txt"), "10"),
+    ] {
+        let runs = cross_check::<Day10>(Part::Two, input).unwrap();
+        assert_eq!(runs.len(), 2);
+        assert!(runs.iter().all(|run| run.answer == expected));
+    }
+}
@@ -0,0 +1,93 @@
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{Answer, Grid, Overflow, Part, Solution};
+use log::trace;
+
+pub struct Day11;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day11>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day11, R>(Part::One, reader)
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Grid::parse(input)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<Answer, Error> {
+        let mut expand_xs = Vec::<usize>::new();
+        let mut expand_ys = Vec::<usize>::new();
+
+        for x in 0..grid.width() {
+            if grid.column(x).all(|c| *c == b'.') {
+                expand_xs.push(x);
+            }
+        }
+
+        for y in 0..grid.height() {
+            if grid.row(y).iter().all(|c| *c == b'.') {
+                expand_ys.push(y);
+            }
+        }
+
+        let expanded_width = grid.width() + expand_xs.len();
+
+        let mut expanded_grid = Vec::<u8>::new();
+
+        for y in 0..grid.height() {
+            if expand_ys.contains(&y) {
+                expanded_grid.extend([b'.'].repeat(expanded_width));
+            }
+            for x in 0..grid.width() {
+                if expand_xs.contains(&x) {
+                    expanded_grid.push(b'.');
+                }
+                expanded_grid.push(grid[(x, y)]);
+            }
+        }
+
+        let expanded_grid = Grid::from_cells(expanded_width, expanded_grid)?;
+
+        let mut galaxy_poses = Vec::<(usize, usize)>::new();
+
+        for (x, y) in expanded_grid.positions() {
+            if expanded_grid[(x, y)] == b'#' {
+                galaxy_poses.push((x, y));
+            }
+        }
+
+        let mut sum_distances = 0usize;
+
+        for i in 0..galaxy_poses.len() {
+            for j in i..galaxy_poses.len() {
+                let (pos_i_x, pos_i_y) = galaxy_poses[i];
+                let (pos_j_x, pos_j_y) = galaxy_poses[j];
+                let distance = pos_i_x.abs_diff(pos_j_x) + pos_i_y.abs_diff(pos_j_y);
+                trace!(
+                    "{} ({}, {}) {} ({}, {}) {}",
+                    i,
+                    pos_i_x,
+                    pos_i_y,
+                    j,
+                    pos_j_x,
+                    pos_j_y,
+                    distance
+                );
+                sum_distances = sum_distances.checked_add(distance).ok_or(Overflow)?;
+            }
+        }
+
+        Ok(sum_distances.into())
+    }
+}
@@ -0,0 +1,151 @@
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{param, Answer, Grid, Overflow, Param, Part, Solution};
+use log::{debug, trace};
+
+pub struct Day11;
+
+/// Solves part 2 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day11>(Part::Two, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day11, R>(Part::Two, reader)
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "expansion",
+        default: "1000000",
+        help: "rows or columns each empty row or column stands for",
+    }];
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Grid::parse(input)
+    }
+
+    fn part2(grid: &Self::Parsed) -> Result<Answer, Error> {
+        let expansion = param::<Self, usize>("expansion")?;
+        if expansion == 0 {
+            return Err(Error::msg("expansion must be at least 1"));
+        }
+        let mut expand_xs = Vec::<usize>::new();
+        let mut expand_ys = Vec::<usize>::new();
+
+        for x in 0..grid.width() {
+            if grid.column(x).all(|c| *c == b'.') {
+                expand_xs.push(x);
+            }
+        }
+
+        for y in 0..grid.height() {
+            if grid.row(y).iter().all(|c| *c == b'.') {
+                expand_ys.push(y);
+            }
+        }
+
+        let expanded_width = grid.width() + expand_xs.len();
+
+        let mut expanded_grid = Vec::<u8>::new();
+
+        for y in 0..grid.height() {
+            if expand_ys.contains(&y) {
+                expanded_grid.extend([b'+'].repeat(expanded_width));
+            }
+            for x in 0..grid.width() {
+                if expand_xs.contains(&x) {
+                    expanded_grid.push(b'+');
+                }
+                expanded_grid.push(grid[(x, y)]);
+            }
+        }
+
+        let expanded_grid = Grid::from_cells(expanded_width, expanded_grid)?;
+
+        debug!("{}", expanded_grid);
+
+        let mut galaxy_poses = Vec::<(usize, usize)>::new();
+
+        // each `+` sits next to an empty row or column and stands in for the `expansion - 1`
+        // copies of it, so it is dropped from the position and they are added instead
+        let mut extra_y = 0usize;
+        let mut markers_y = 0;
+
+        for y in 0..expanded_grid.height() {
+            if expanded_grid.row(y).iter().all(|c| *c == b'+') {
+                extra_y = extra_y.checked_add(expansion - 1).ok_or(Overflow)?;
+                markers_y += 1;
+            }
+            let mut extra_x = 0usize;
+            let mut markers_x = 0;
+            for x in 0..expanded_grid.width() {
+                if expanded_grid[(x, y)] == b'+' {
+                    extra_x = extra_x.checked_add(expansion - 1).ok_or(Overflow)?;
+                    markers_x += 1;
+                }
+                if expanded_grid[(x, y)] == b'#' {
+                    let galaxy_x = (x - markers_x).checked_add(extra_x).ok_or(Overflow)?;
+                    let galaxy_y = (y - markers_y).checked_add(extra_y).ok_or(Overflow)?;
+                    galaxy_poses.push((galaxy_x, galaxy_y));
+                }
+            }
+        }
+
+        /*
+        let expanded_xs = expand_xs
+            .iter()
+            .enumerate()
+            .map(|(i, v)| i + v)
+            .collect::<Vec<_>>();
+        let expanded_ys = expand_xs
+            .iter()
+            .enumerate()
+            .map(|(i, v)| i + v)
+            .collect::<Vec<_>>();
+        */
+
+        let mut sum_distances = 0usize;
+
+        for i in 0..galaxy_poses.len() {
+            for j in i..galaxy_poses.len() {
+                let (pos_i_x, pos_i_y) = galaxy_poses[i];
+                let (pos_j_x, pos_j_y) = galaxy_poses[j];
+                let distance = pos_i_x
+                    .abs_diff(pos_j_x)
+                    .checked_add(pos_i_y.abs_diff(pos_j_y))
+                    .ok_or(Overflow)?;
+                /*
+                for expand_x in expanded_xs.iter() {
+                    if between(pos_i_x, pos_j_x, *expand_x) {
+                        distance += 8;
+                    }
+                }
+                for expand_y in expanded_ys.iter() {
+                    if between(pos_i_y, pos_j_y, *expand_y) {
+                        distance += 98;
+                    }
+                }
+                */
+                trace!(
+                    "{} ({}, {}) {} ({}, {}) {}",
+                    i,
+                    pos_i_x,
+                    pos_i_y,
+                    j,
+                    pos_j_x,
+                    pos_j_y,
+                    distance
+                );
+                sum_distances = sum_distances.checked_add(distance).ok_or(Overflow)?;
+            }
+        }
+
+        Ok(sum_distances.into())
+    }
+}
@@ -0,0 +1,39 @@
+#[test]
+fn example() {
+    let answer = aoc2023_11_2::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "82000210");
+}
+
+#[test]
+fn smaller_expansions() {
+    use aoc2023_common::{with_params, Params};
+
+    for (expansion, expected) in [("2", "374"), ("10", "1030"), ("100", "8410")] {
+        let mut params = Params::default();
+        params.set("expansion", expansion);
+        let answer = with_params(params, || {
+            aoc2023_11_2::solve(include_str!("examples/example.txt"))
+        });
+        assert_eq!(answer.unwrap(), expected);
+    }
+}
+
+#[test]
+fn overflowing_expansion() {
+    use aoc2023_common::{with_params, Overflow, Params};
+
+    let mut params = Params::default();
+    params.set("expansion", usize::MAX.to_string());
+    let error = with_params(params, || {
+        aoc2023_11_2::solve(include_str!("examples/example.txt"))
+    })
+    .unwrap_err();
+    assert!(error.downcast_ref::<Overflow>().is_some(), "{:#}", error);
+}
+
+#[test]
+fn empty_first_row() {
+    // only the empty columns, not every column of the empty row, widen the image
+    let answer = aoc2023_11_2::solve("....\n#..#\n").unwrap();
+    assert_eq!(answer, "2000001");
+}
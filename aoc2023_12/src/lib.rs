@@ -0,0 +1,145 @@
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, Answer, Overflow, Part, Solution};
+use log::trace;
+
+pub struct Day12;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day12>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day12, R>(Part::One, reader)
+}
+
+pub struct Record {
+    groups: Vec<usize>,
+    status: Vec<u8>,
+}
+
+fn is_combo_valid(current: &[u8], groups: &[usize]) -> bool {
+    trace!(
+        "is_combo_valid({}, {:?})",
+        String::from_utf8_lossy(current),
+        groups
+    );
+
+    let mut run_length = 0;
+    let mut group_i = 0;
+    for b in current.iter() {
+        if *b == b'#' {
+            run_length += 1;
+            continue;
+        }
+        if run_length != 0 {
+            if group_i >= groups.len() || run_length != groups[group_i] {
+                trace!("group {} {}", group_i, false);
+                return false;
+            }
+            run_length = 0;
+            group_i += 1;
+        }
+    }
+    if run_length != 0 {
+        if group_i >= groups.len() || run_length != groups[group_i] {
+            trace!("end {}", false);
+            return false;
+        }
+        group_i += 1;
+    }
+    let ret = group_i == groups.len();
+    trace!("length {}", ret);
+    ret
+}
+
+/// Counts the arrangements of `status` that match `groups` by trying every assignment of its
+/// unknown springs.
+pub fn count_combos(status: &[u8], groups: &[usize]) -> u64 {
+    let mut unknown_poses = Vec::<usize>::new();
+    for (i, c) in status.iter().enumerate() {
+        if *c == b'?' {
+            unknown_poses.push(i);
+        }
+    }
+
+    let mut valid_combo_count = 0;
+    let mut current = status
+        .iter()
+        .map(|b| match b {
+            b'?' => b'.',
+            x => *x,
+        })
+        .collect::<Vec<_>>();
+
+    loop {
+        if is_combo_valid(&current, groups) {
+            valid_combo_count += 1;
+        }
+
+        // increment
+        let mut incremented = false;
+        for unknown_pos in unknown_poses.iter() {
+            let unknown_pos = *unknown_pos;
+            if current[unknown_pos] == b'.' {
+                current[unknown_pos] = b'#';
+                incremented = true;
+                break;
+            }
+            current[unknown_pos] = b'.';
+        }
+        if !incremented {
+            break;
+        }
+    }
+
+    valid_combo_count
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Parsed = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut records = Vec::new();
+
+        for line in lines(input) {
+            let (status, groups_str) = line
+                .split_once(' ')
+                .ok_or_else(|| line.error_here("missing space"))?;
+            if let Some(i) = status.bytes().position(|b| !b".#?".contains(&b)) {
+                return Err(line.error(i, "springs must be ., # or ?").into());
+            }
+            let groups = groups_str
+                .split(',')
+                .map(|s| match line.parse::<usize>(s)? {
+                    0 => Err(line.error_at(s, "groups must have at least one spring")),
+                    group => Ok(group),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            records.push(Record {
+                groups,
+                status: status.as_bytes().to_vec(),
+            });
+        }
+
+        Ok(records)
+    }
+
+    fn part1(records: &Self::Parsed) -> Result<Answer, Error> {
+        let mut total_combo_count = 0u64;
+
+        for Record { groups, status } in records.iter() {
+            total_combo_count = total_combo_count
+                .checked_add(count_combos(status, groups))
+                .ok_or(Overflow)?;
+        }
+
+        Ok(total_combo_count.into())
+    }
+}
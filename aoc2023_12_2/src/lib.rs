@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, param, Answer, CheckedIterator, Overflow, Param, Part, Solution};
+use log::{debug, trace};
+
+pub struct Day12;
+
+/// Solves part 2 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day12>(Part::Two, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day12, R>(Part::Two, reader)
+}
+
+pub struct Record {
+    groups: Vec<usize>,
+    status: Vec<u8>,
+}
+
+type Cache = HashMap<State, u64>;
+
+#[derive(Eq, Hash, PartialEq)]
+struct State {
+    first: Option<u8>,
+    current: Vec<u8>,
+    active_group: Option<usize>,
+    groups: Vec<usize>,
+}
+
+/// Counts the arrangements of `current` that match `groups`, without unfolding either.
+pub fn count_combos(current: &[u8], groups: &[usize]) -> Result<u64, Overflow> {
+    let mut cache = Cache::new();
+    count_combos_recursive(
+        &mut cache,
+        0,
+        current.first().cloned(),
+        exclude_first(current),
+        None,
+        groups,
+    )
+}
+
+fn count_combos_recursive(
+    cache: &mut Cache,
+    depth: usize,
+    first: Option<u8>,
+    current: &[u8],
+    active_group: Option<usize>,
+    groups: &[usize],
+) -> Result<u64, Overflow> {
+    trace!(
+        "count_combos_recursive({}, {}, {} {}, {:?}, {} {:?})",
+        depth,
+        first.map(|f| f as char).unwrap_or('N'),
+        current.len(),
+        String::from_utf8_lossy(current),
+        active_group,
+        groups.len(),
+        groups
+    );
+
+    let state = State {
+        first,
+        current: current.to_vec(),
+        active_group,
+        groups: groups.to_vec(),
+    };
+
+    if let Some(ret) = cache.get(&state) {
+        return Ok(*ret);
+    }
+
+    let remaining_damaged = groups
+        .iter()
+        .copied()
+        .checked_sum()?
+        .checked_add(active_group.unwrap_or_default())
+        .ok_or(Overflow)?;
+    // springs the remaining groups need, counting one gap between each of them
+    let remaining_needed = remaining_damaged
+        .checked_add(groups.len().saturating_sub(1))
+        .ok_or(Overflow)?;
+
+    let first = match first {
+        None => {
+            trace!(
+                "first None active_group {:?} remaining_damaged {}",
+                active_group,
+                remaining_damaged
+            );
+            let ret = if remaining_damaged == 0 { 1 } else { 0 };
+            trace!("{} return {}", depth, ret);
+            cache.insert(state, ret);
+            return Ok(ret);
+        }
+        Some(f) => f,
+    };
+
+    let max_remaining_damaged = max_damaged(current);
+
+    // early exit heuristics
+
+    if max_remaining_damaged + 2 < remaining_damaged {
+        return Ok(0);
+    }
+
+    if remaining_needed > current.len() + 1 {
+        return Ok(0);
+    }
+
+    match first {
+        b'.' => match active_group {
+            None => {
+                // just advance current
+                let ret = count_combos_recursive(
+                    cache,
+                    depth + 1,
+                    current.first().cloned(),
+                    exclude_first(current),
+                    active_group,
+                    groups,
+                )?;
+                cache.insert(state, ret);
+                Ok(ret)
+            }
+            Some(g) => {
+                // ending a group
+                if g != 0 {
+                    // can't if group isn't exhausted
+                    trace!(
+                        "group ended first {} current {} active_group {} groups {:?}",
+                        first as char,
+                        String::from_utf8_lossy(current),
+                        g,
+                        groups
+                    );
+                    let ret = 0;
+                    cache.insert(state, ret);
+                    return Ok(ret);
+                }
+                // advance current, end group
+                let ret = count_combos_recursive(
+                    cache,
+                    depth + 1,
+                    current.first().cloned(),
+                    exclude_first(current),
+                    None,
+                    groups,
+                )?;
+                cache.insert(state, ret);
+                Ok(ret)
+            }
+        },
+        b'#' => match active_group {
+            None => {
+                // start group
+                if groups.is_empty() {
+                    let ret = 0;
+                    cache.insert(state, ret);
+                    return Ok(ret);
+                }
+                let ret = count_combos_recursive(
+                    cache,
+                    depth + 1,
+                    current.first().cloned(),
+                    exclude_first(current),
+                    Some(groups[0] - 1),
+                    exclude_first(groups),
+                )?;
+                cache.insert(state, ret);
+                Ok(ret)
+            }
+            Some(g) => {
+                // continue existing group
+                if g == 0 {
+                    // can't if current group has run out
+                    let ret = 0;
+                    cache.insert(state, ret);
+                    return Ok(ret);
+                }
+                // reduce current group by 1
+                let ret = count_combos_recursive(
+                    cache,
+                    depth + 1,
+                    current.first().cloned(),
+                    exclude_first(current),
+                    Some(g - 1),
+                    groups,
+                )?;
+                cache.insert(state, ret);
+                Ok(ret)
+            }
+        },
+        b'?' => {
+            // sum up both alternatives for first, with some pruning
+            let mut count = 0u64;
+            if max_remaining_damaged >= remaining_damaged && remaining_needed <= current.len() {
+                // don't bother trying . if we can't satisfy the groups even making
+                // all remaining ones #
+                count = count_combos_recursive(
+                    cache,
+                    depth + 1,
+                    Some(b'.'),
+                    current,
+                    active_group,
+                    groups,
+                )?;
+            }
+            if remaining_damaged != 0 {
+                let damaged = count_combos_recursive(
+                    cache,
+                    depth + 1,
+                    Some(b'#'),
+                    current,
+                    active_group,
+                    groups,
+                )?;
+                count = count.checked_add(damaged).ok_or(Overflow)?;
+            }
+            cache.insert(state, count);
+            Ok(count)
+        }
+        _ => unreachable!("springs are checked while parsing"),
+    }
+}
+
+fn exclude_first<T>(s: &[T]) -> &[T] {
+    if s.is_empty() {
+        &[]
+    } else {
+        &s[1..]
+    }
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "copies",
+        default: "5",
+        help: "times each record is repeated when unfolding it",
+    }];
+
+    type Parsed = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut records = Vec::new();
+
+        for line in lines(input) {
+            let (status, groups_str) = line
+                .split_once(' ')
+                .ok_or_else(|| line.error_here("missing space"))?;
+            if let Some(i) = status.bytes().position(|b| !b".#?".contains(&b)) {
+                return Err(line.error(i, "springs must be ., # or ?").into());
+            }
+            let groups = groups_str
+                .split(',')
+                .map(|s| match line.parse::<usize>(s)? {
+                    0 => Err(line.error_at(s, "groups must have at least one spring")),
+                    group => Ok(group),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            records.push(Record {
+                groups,
+                status: status.as_bytes().to_vec(),
+            });
+        }
+
+        Ok(records)
+    }
+
+    fn part2(records: &Self::Parsed) -> Result<Answer, Error> {
+        let copies = param::<Self, usize>("copies")?;
+        let mut total_combo_count = 0u64;
+
+        for Record { groups, status } in records.iter() {
+            let unfolded_status = unfold_status(status, copies);
+            let unfolded_groups = unfold_groups(groups, copies);
+
+            debug!(
+                "processing {} {:?}",
+                String::from_utf8_lossy(&unfolded_status),
+                unfolded_groups
+            );
+
+            let valid_combo_count = count_combos(&unfolded_status, &unfolded_groups)?;
+
+            /*
+            let mut unknown_poses = Vec::<usize>::new();
+            for (i, c) in unfolded_status.iter().enumerate() {
+                if *c == b'?' {
+                    unknown_poses.push(i);
+                }
+            }
+
+            let mut valid_combo_count = 0;
+            let mut current = unfolded_status
+                .iter()
+                .map(|b| match b {
+                    b'?' => b'.',
+                    x => *x,
+                })
+                .collect::<Vec<_>>();
+
+            loop {
+                if is_combo_valid(&current, &unfolded_groups) {
+                    valid_combo_count += 1;
+                }
+
+                // increment
+                let mut incremented = false;
+                for unknown_pos in unknown_poses.iter() {
+                    let unknown_pos = *unknown_pos;
+                    if current[unknown_pos] == b'.' {
+                        current[unknown_pos] = b'#';
+                        incremented = true;
+                        break;
+                    }
+                    current[unknown_pos] = b'.';
+                }
+                if !incremented {
+                    break;
+                }
+            }
+            */
+
+            total_combo_count = total_combo_count
+                .checked_add(valid_combo_count)
+                .ok_or(Overflow)?;
+        }
+
+        Ok(total_combo_count.into())
+    }
+}
+
+fn max_damaged(statuses: &[u8]) -> usize {
+    statuses
+        .iter()
+        .filter(|s| **s == b'#' || **s == b'?')
+        .count()
+}
+
+fn unfold_groups(groups: &[usize], copies: usize) -> Vec<usize> {
+    groups.repeat(copies)
+}
+
+fn unfold_status(status: &[u8], copies: usize) -> Vec<u8> {
+    vec![status; copies].join(&b'?')
+}
@@ -0,0 +1,23 @@
+use aoc2023_common::InputError;
+
+#[test]
+fn example() {
+    let answer = aoc2023_12_2::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "525152");
+}
+
+#[test]
+fn empty_group() {
+    let error = aoc2023_12_2::solve("???.### 1,0,3\n").unwrap_err();
+    let error = error.downcast_ref::<InputError>().unwrap();
+    assert_eq!((error.line(), error.column()), (Some(1), Some(11)));
+}
+
+#[test]
+fn overflowing_groups() {
+    use aoc2023_common::Overflow;
+
+    let input = format!("? {},1\n", usize::MAX);
+    let error = aoc2023_12_2::solve(&input).unwrap_err();
+    assert!(error.downcast_ref::<Overflow>().is_some(), "{:#}", error);
+}
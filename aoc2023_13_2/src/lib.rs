@@ -0,0 +1,173 @@
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, Answer, Grid, InputError, Overflow, Part, Solution};
+use log::trace;
+
+pub struct Day13;
+
+/// Solves part 2 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day13>(Part::Two, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day13, R>(Part::Two, reader)
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Parsed = Vec<Grid<u8>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let lines = lines(input).collect::<Vec<_>>();
+
+        lines
+            .split(|line| line.is_empty())
+            .filter(|pattern| !pattern.is_empty())
+            .map(|pattern| Grid::parse_lines(pattern.iter().copied()))
+            .collect()
+    }
+
+    fn part2(patterns: &Self::Parsed) -> Result<Answer, Error> {
+        let mut total = 0usize;
+
+        'patterns: for (i, pattern) in patterns.iter().enumerate() {
+            let mut pattern = pattern.clone();
+            let orig_answer = process_pattern(&pattern, 0);
+            for smudge_pos in pattern.positions() {
+                let orig = pattern[smudge_pos];
+                pattern[smudge_pos] = if orig == b'.' { b'#' } else { b'.' };
+                let answer = process_pattern(&pattern, orig_answer);
+                if answer != 0 && answer != orig_answer {
+                    total = total.checked_add(answer).ok_or(Overflow)?;
+                    continue 'patterns;
+                }
+                pattern[smudge_pos] = orig;
+            }
+            return Err(InputError::new(format!(
+                "pattern {} has no reflection with a smudge fixed",
+                i + 1
+            ))
+            .into());
+        }
+
+        Ok(total.into())
+    }
+}
+
+fn process_pattern(pattern: &Grid<u8>, exclude: usize) -> usize {
+    for row in 0..pattern.height() - 1 {
+        if test_row(pattern, row) {
+            let ret = 100 * (row + 1);
+            if ret != exclude {
+                return ret;
+            }
+        }
+    }
+    for col in 0..pattern.width() - 1 {
+        if test_col(pattern, col) {
+            let ret = col + 1;
+            if ret != exclude {
+                return ret;
+            }
+        }
+    }
+    0
+}
+
+fn test_col(pattern: &Grid<u8>, col: usize) -> bool {
+    // refers to gap right of col
+    for right_col in col + 1..pattern.width() {
+        if right_col - col - 1 > col {
+            break;
+        }
+        let left_col = col - (right_col - col - 1);
+        if pattern.column(left_col).ne(pattern.column(right_col)) {
+            return false;
+        }
+    }
+    true
+}
+
+fn test_row(pattern: &Grid<u8>, row: usize) -> bool {
+    trace!("test_row {}", row);
+    // refers to gap below row
+    for bottom_row in row + 1..pattern.height() {
+        if bottom_row - row - 1 > row {
+            break;
+        }
+        let top_row = row - (bottom_row - row - 1);
+        trace!(
+            "test_row row {} top_row {} bottom_row {}",
+            row,
+            top_row,
+            bottom_row
+        );
+        if pattern.row(top_row) != pattern.row(bottom_row) {
+            return false;
+        }
+    }
+    trace!("test_row true");
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VERTICAL: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+";
+
+    const HORIZONTAL: &str = "\
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+    #[test]
+    fn test_col_finds_reflection() {
+        let pattern = Grid::parse(VERTICAL).unwrap();
+        assert!(test_col(&pattern, 4));
+        assert!(!test_col(&pattern, 3));
+        assert!(!test_col(&pattern, 5));
+    }
+
+    #[test]
+    fn test_row_finds_reflection() {
+        let pattern = Grid::parse(HORIZONTAL).unwrap();
+        assert!(test_row(&pattern, 3));
+        assert!(!test_row(&pattern, 2));
+        assert!(!test_row(&pattern, 4));
+    }
+
+    #[test]
+    fn reflection_at_edges() {
+        // only one row or column needs to match when the line is next to an edge
+        let pattern = Grid::parse("##.\n##.\n..#\n").unwrap();
+        assert!(test_col(&pattern, 0));
+        assert!(!test_col(&pattern, 1));
+        assert!(test_row(&pattern, 0));
+        assert!(!test_row(&pattern, 1));
+    }
+
+    #[test]
+    fn process_pattern_skips_excluded() {
+        let pattern = Grid::parse(VERTICAL).unwrap();
+        assert_eq!(process_pattern(&pattern, 0), 5);
+        assert_eq!(process_pattern(&pattern, 5), 0);
+    }
+}
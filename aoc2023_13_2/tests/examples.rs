@@ -0,0 +1,12 @@
+#[test]
+fn example() {
+    let answer = aoc2023_13_2::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "400");
+}
+
+#[test]
+fn example_saved_on_windows() {
+    let example = include_str!("examples/example.txt").replace('\n', "\r\n");
+    let answer = aoc2023_13_2::solve(&format!("\u{feff}{}\r\n", example)).unwrap();
+    assert_eq!(answer, "400");
+}
@@ -0,0 +1,61 @@
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{Answer, CheckedIterator, Grid, Part, Solution};
+
+pub struct Day14;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day14>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day14, R>(Part::One, reader)
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Grid::parse(input)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<Answer, Error> {
+        let mut grid = grid.clone();
+
+        while roll_north(&mut grid) {}
+
+        let total_load = grid
+            .positions()
+            .filter(|pos| grid[*pos] == b'O')
+            .map(|(_, y)| grid.height() - y)
+            .checked_sum()?;
+
+        Ok(total_load.into())
+    }
+}
+
+fn roll_north(grid: &mut Grid<u8>) -> bool {
+    let mut any_moved = false;
+
+    for (x, y) in grid.positions() {
+        if grid[(x, y)] != b'.' {
+            continue;
+        }
+        if y + 1 >= grid.height() {
+            continue;
+        }
+        if grid[(x, y + 1)] != b'O' {
+            continue;
+        }
+        grid[(x, y)] = b'O';
+        grid[(x, y + 1)] = b'.';
+        any_moved = true;
+    }
+
+    any_moved
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{
+    param, Answer, CheckedIterator, Grid, Overflow, Param, Part, Progress, Solution, Strategy,
+};
+use log::debug;
+
+pub struct Day14;
+
+/// Solves part 2 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day14>(Part::Two, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day14, R>(Part::Two, reader)
+}
+
+// rolls north, west, south then east by rotating each of them to the north in turn
+fn cycle(grid: &mut Grid<u8>) {
+    for _ in 0..4 {
+        while roll_north(grid) {}
+        *grid = grid.rotate_clockwise();
+    }
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "cycles",
+        default: "1000000000",
+        help: "spin cycles to run",
+    }];
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Grid::parse(input)
+    }
+
+    fn part2(grid: &Self::Parsed) -> Result<Answer, Error> {
+        load_after_hash_map(grid)
+    }
+
+    fn strategies(part: Part) -> Vec<Strategy<Self::Parsed>> {
+        match part {
+            Part::One => Vec::new(),
+            Part::Two => vec![
+                Strategy {
+                    name: "hash map",
+                    solve: load_after_hash_map,
+                },
+                Strategy {
+                    name: "Brent",
+                    solve: load_after_brent,
+                },
+            ],
+        }
+    }
+}
+
+// remembers every grid seen, and skips ahead by whole loops once one comes round again
+fn load_after_hash_map(grid: &Grid<u8>) -> Result<Answer, Error> {
+    let cycles = param::<Day14, usize>("cycles")?;
+    let mut grid = grid.clone();
+
+    let mut seen = HashMap::<Grid<u8>, usize>::new();
+
+    let progress = Progress::new(module_path!(), Some(cycles as u64));
+    let mut i = 0;
+    while i != cycles {
+        if let Some(when_seen) = seen.get(&grid) {
+            let cycle_length = i - when_seen;
+            let addable_times = (cycles - i - 1) / cycle_length;
+            let iters_to_add = addable_times * cycle_length;
+            debug!(
+                "{} seen at {} cycle_length {} addable_times {} iters_to_add {}",
+                i, when_seen, cycle_length, addable_times, iters_to_add
+            );
+            i += iters_to_add;
+            progress.advance(iters_to_add as u64)?;
+        }
+
+        if i == cycles {
+            break;
+        }
+
+        seen.insert(grid.clone(), i);
+        cycle(&mut grid);
+        i += 1;
+        progress.tick()?;
+    }
+
+    Ok(north_load(&grid)?.into())
+}
+
+// finds the loop with Brent's algorithm, which keeps only two grids rather than every one seen,
+// then runs just as many cycles as reaching the same point of the loop takes
+fn load_after_brent(start: &Grid<u8>) -> Result<Answer, Error> {
+    let cycles = param::<Day14, usize>("cycles")?;
+    let progress = Progress::new(module_path!(), None);
+
+    // the loop's length: the hare runs ahead of the tortoise, which jumps to it each time the
+    // distance between them reaches the next power of two
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    cycle(&mut hare);
+    progress.tick()?;
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        cycle(&mut hare);
+        length += 1;
+        progress.tick()?;
+    }
+
+    // where the loop starts: the first point a grid `length` cycles ahead matches
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    for _ in 0..length {
+        cycle(&mut hare);
+        progress.tick()?;
+    }
+    let mut loop_start = 0;
+    while tortoise != hare {
+        cycle(&mut tortoise);
+        cycle(&mut hare);
+        loop_start += 1;
+        progress.tick()?;
+    }
+    debug!("loop_start {} length {}", loop_start, length);
+
+    // the tortoise is at the start of the loop, so go round it only as far as the leftover cycles
+    let (mut grid, remaining) = if cycles < loop_start {
+        (start.clone(), cycles)
+    } else {
+        (tortoise, (cycles - loop_start) % length)
+    };
+    for _ in 0..remaining {
+        cycle(&mut grid);
+        progress.tick()?;
+    }
+
+    Ok(north_load(&grid)?.into())
+}
+
+fn north_load(grid: &Grid<u8>) -> Result<usize, Overflow> {
+    grid.positions()
+        .filter(|pos| grid[*pos] == b'O')
+        .map(|(_, y)| grid.height() - y)
+        .checked_sum()
+}
+
+fn roll_north(grid: &mut Grid<u8>) -> bool {
+    let mut any_moved = false;
+
+    for (x, y) in grid.positions() {
+        if grid[(x, y)] != b'.' {
+            continue;
+        }
+        if y + 1 >= grid.height() {
+            continue;
+        }
+        if grid[(x, y + 1)] != b'O' {
+            continue;
+        }
+        grid[(x, y)] = b'O';
+        grid[(x, y + 1)] = b'.';
+        any_moved = true;
+    }
+
+    any_moved
+}
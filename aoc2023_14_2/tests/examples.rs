@@ -0,0 +1,29 @@
+#[test]
+fn example() {
+    let answer = aoc2023_14_2::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "64");
+}
+
+#[test]
+fn strategies_agree() {
+    use aoc2023_14_2::Day14;
+    use aoc2023_common::{cross_check, with_params, Params, Part};
+
+    // few enough cycles to stop before the loop, then partway round it
+    for cycles in ["1000000000", "2", "10"] {
+        let mut params = Params::default();
+        params.set("cycles", cycles);
+        let runs = with_params(params, || {
+            cross_check::<Day14>(Part::Two, include_str!("examples/example.txt"))
+        })
+        .unwrap();
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].answer, runs[1].answer, "{} cycles", cycles);
+    }
+}
+
+#[test]
+fn platform_wider_than_tall() {
+    let answer = aoc2023_14_2::solve("O.#.O.\n.O..#O\n#..O..\n").unwrap();
+    assert_eq!(answer, "8");
+}
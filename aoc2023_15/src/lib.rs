@@ -0,0 +1,47 @@
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{Answer, Overflow, Part, Solution};
+
+pub struct Day15;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day15>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day15, R>(Part::One, reader)
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Parsed = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut sections = Vec::new();
+
+        for line in input.lines() {
+            sections.extend(line.as_bytes().split(|b| *b == b',').map(|s| s.to_vec()));
+        }
+
+        Ok(sections)
+    }
+
+    fn part1(sections: &Self::Parsed) -> Result<Answer, Error> {
+        let mut hash_total = 0u64;
+        for section in sections.iter() {
+            let mut hash = 0u16;
+            for b in section.iter().cloned() {
+                hash += b as u16;
+                hash *= 17;
+                hash &= 0xff;
+            }
+            hash_total = hash_total.checked_add(u64::from(hash)).ok_or(Overflow)?;
+        }
+
+        Ok(hash_total.into())
+    }
+}
@@ -1,23 +1,6 @@
-use std::error::Error;
-use std::io::stdin;
+use anyhow::Error;
+use aoc2023_common::Part;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    for line in stdin().lines() {
-        let line = line?;
-
-        let mut hash_total = 0u64;
-        for section in line.as_bytes().split(|b| *b == b',') {
-            let mut hash = 0u16;
-            for b in section.iter().cloned() {
-                hash += b as u16;
-                hash *= 17;
-                hash = hash & 0xff;
-            }
-            hash_total += hash as u64;
-        }
-
-        println!("{}", hash_total);
-    }
-
-    Ok(())
+fn main() -> Result<(), Error> {
+    aoc2023_common::main::<aoc2023_15::Day15>(Part::One)
 }
@@ -0,0 +1,14 @@
+#[test]
+fn example() {
+    let answer = aoc2023_15::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "1320");
+}
+
+#[test]
+fn example_without_final_newline() {
+    let example = include_str!("examples/example.txt");
+    let answer = aoc2023_15::solve(example.trim_end()).unwrap();
+    assert_eq!(answer, "1320");
+    let answer = aoc2023_15::solve(&example.replace('\n', " \r\n")).unwrap();
+    assert_eq!(answer, "1320");
+}
@@ -0,0 +1,111 @@
+use std::array::from_fn;
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, Answer, CheckedIterator, Overflow, Part, Solution};
+
+pub struct Day15;
+
+/// Solves part 2 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day15>(Part::Two, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day15, R>(Part::Two, reader)
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Lense {
+    focal_length: u8,
+    label: Vec<u8>,
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Parsed = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut sections = Vec::new();
+
+        for line in lines(input) {
+            for step in line.as_bytes().split(|b| *b == b',') {
+                let valid = match step.iter().position(|b| *b == b'=' || *b == b'-') {
+                    Some(i) if step[i] == b'-' => i + 1 == step.len(),
+                    Some(i) => i + 2 == step.len() && step[i + 1].is_ascii_digit(),
+                    None => false,
+                };
+                if !valid {
+                    return Err(line
+                        .error_at(
+                            step,
+                            format!(
+                                "invalid step {:?}, expected label- or label=digit",
+                                String::from_utf8_lossy(step)
+                            ),
+                        )
+                        .into());
+                }
+                sections.push(step.to_vec());
+            }
+        }
+
+        Ok(sections)
+    }
+
+    fn part2(sections: &Self::Parsed) -> Result<Answer, Error> {
+        let mut boxes: [Vec<Lense>; 256] = from_fn(|_| Vec::<Lense>::new());
+
+        for section in sections.iter() {
+            let mut hash = 0usize;
+            let mut label = Vec::<u8>::new();
+            let mut found_equal = false;
+            for b in section.iter().cloned() {
+                if b == b'-' {
+                    let the_box = &mut boxes[hash];
+                    if let Some(pos) = the_box.iter().position(|lense| lense.label == label) {
+                        the_box.remove(pos);
+                    }
+                    break;
+                }
+                if b == b'=' {
+                    found_equal = true;
+                    continue;
+                }
+                if found_equal {
+                    let the_box = &mut boxes[hash];
+                    let focal_length = b - b'0';
+                    if let Some(lense) = the_box.iter_mut().find(|lense| lense.label == label) {
+                        lense.focal_length = focal_length;
+                    } else {
+                        let lense = Lense {
+                            focal_length,
+                            label,
+                        };
+                        the_box.push(lense);
+                    }
+                    break;
+                }
+                label.push(b);
+                hash += b as usize;
+                hash *= 17;
+                hash &= 0xff;
+            }
+        }
+
+        let mut total_power = 0usize;
+
+        for (box_num, the_box) in boxes.iter().enumerate() {
+            for (slot_num, lense) in the_box.iter().enumerate() {
+                let power = [box_num + 1, slot_num + 1, usize::from(lense.focal_length)]
+                    .into_iter()
+                    .checked_product()?;
+                total_power = total_power.checked_add(power).ok_or(Overflow)?;
+            }
+        }
+
+        Ok(total_power.into())
+    }
+}
@@ -0,0 +1,134 @@
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{Answer, Direction, Grid, InputError, Part, Solution};
+use log::debug;
+
+pub struct Day16;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day16>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day16, R>(Part::One, reader)
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let grid = Grid::parse_with(input, |b| {
+            if b"./\\|-".contains(&b) {
+                Ok(b)
+            } else {
+                Err(Error::msg(format!("invalid tile {:?}", b as char)))
+            }
+        })?;
+        if grid.cells().is_empty() {
+            return Err(InputError::new("empty grid").into());
+        }
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<Answer, Error> {
+        let mut direction_info = Grid::new(grid.width(), grid.height(), 0u8);
+
+        shoot_beam(grid, &mut direction_info, (0, 0), Direction::Right);
+
+        debug!(
+            "{}",
+            direction_info.map(|mask| {
+                if mask.count_ones() > 1 {
+                    return b'0' + mask.count_ones() as u8;
+                }
+                match mask {
+                    0b0000 => b'.',
+                    0b0001 => b'^',
+                    0b0010 => b'>',
+                    0b0100 => b'V',
+                    0b1000 => b'<',
+                    _ => panic!("invalid direction mask"),
+                }
+            })
+        );
+
+        let energized = direction_info
+            .cells()
+            .iter()
+            .cloned()
+            .filter(|d| *d != 0u8)
+            .count();
+
+        Ok(energized.into())
+    }
+}
+
+fn shoot_beam(
+    grid: &Grid<u8>,
+    direction_info: &mut Grid<u8>,
+    pos: (usize, usize),
+    direction: Direction,
+) {
+    let mut beams = vec![(pos, direction)];
+
+    while let Some((pos, direction)) = beams.pop() {
+        let direction_mask = 1 << direction as u8;
+
+        if direction_info[pos] & direction_mask != 0 {
+            continue;
+        }
+
+        direction_info[pos] |= direction_mask;
+
+        let t = grid[pos];
+
+        let mut proceed = |new_direction| {
+            if let Some(new_pos) = grid.step(pos, new_direction) {
+                beams.push((new_pos, new_direction));
+            }
+        };
+
+        // straight
+        if t == b'.'
+            || (t == b'-' && direction.is_horizontal())
+            || (t == b'|' && direction.is_vertical())
+        {
+            proceed(direction);
+        }
+
+        // mirrored
+        if t == b'/' {
+            let new_direction = if direction.is_horizontal() {
+                direction.turn_left()
+            } else {
+                direction.turn_right()
+            };
+            proceed(new_direction);
+        }
+
+        if t == b'\\' {
+            let new_direction = if direction.is_horizontal() {
+                direction.turn_right()
+            } else {
+                direction.turn_left()
+            };
+            proceed(new_direction);
+        }
+
+        // split
+        if t == b'|' && direction.is_horizontal() {
+            proceed(Direction::Down);
+            proceed(Direction::Up);
+        }
+
+        if t == b'-' && direction.is_vertical() {
+            proceed(Direction::Right);
+            proceed(Direction::Left);
+        }
+    }
+}
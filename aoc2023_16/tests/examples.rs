@@ -0,0 +1,5 @@
+#[test]
+fn example() {
+    let answer = aoc2023_16::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "46");
+}
@@ -0,0 +1,12 @@
+#[test]
+fn example() {
+    let answer = aoc2023_16_2::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "51");
+}
+
+#[test]
+fn best_from_the_bottom() {
+    // only a beam entering the middle column upwards reaches the splitter from below
+    let answer = aoc2023_16_2::solve(".-.\n...\n...\n").unwrap();
+    assert_eq!(answer, "5");
+}
@@ -0,0 +1,127 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashSet};
+use std::io::BufRead;
+
+use anyhow::{Context, Error};
+use aoc2023_common::{Answer, Direction, Grid, InputError, Part, Solution};
+
+pub struct Day17;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day17>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day17, R>(Part::One, reader)
+}
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct State {
+    direction: Direction,
+    direction_count: u8,
+    pos: (usize, usize),
+}
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct StateWithCost {
+    cost: usize,
+    state: State,
+}
+
+impl Ord for StateWithCost {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cost.cmp(&other.cost)
+    }
+}
+
+impl PartialOrd for StateWithCost {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let grid = Grid::parse_with(input, |b| {
+            (b as char)
+                .to_digit(10)
+                .map(|d| d as u8)
+                .context("invalid heat loss")
+        })?;
+        if grid.cells().is_empty() {
+            return Err(InputError::new("empty grid").into());
+        }
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<Answer, Error> {
+        let count = shortest_path(
+            grid,
+            &State {
+                direction: Direction::Right,
+                direction_count: 0,
+                pos: (0, 0),
+            },
+        )
+        .ok_or_else(|| InputError::new("no path to the bottom right block"))?;
+
+        Ok(count.into())
+    }
+}
+
+fn shortest_path(grid: &Grid<u8>, starting_state: &State) -> Option<usize> {
+    let mut candidates = BinaryHeap::<Reverse<StateWithCost>>::new();
+    let mut visited = HashSet::<State>::new();
+
+    candidates.push(Reverse(StateWithCost {
+        cost: 0,
+        state: starting_state.clone(),
+    }));
+
+    while let Some(Reverse(candidate)) = candidates.pop() {
+        if !visited.insert(candidate.state.clone()) {
+            continue;
+        }
+
+        if candidate.state.pos == (grid.width() - 1, grid.height() - 1) {
+            return Some(candidate.cost);
+        }
+
+        for direction in Direction::ALL {
+            if direction == candidate.state.direction.reverse() {
+                continue;
+            }
+
+            let straight = direction == candidate.state.direction;
+            // at most three blocks in a straight line
+            if straight && candidate.state.direction_count >= 3 {
+                continue;
+            }
+
+            let Some(new_pos) = grid.step(candidate.state.pos, direction) else {
+                continue;
+            };
+
+            candidates.push(Reverse(StateWithCost {
+                cost: candidate.cost + grid[new_pos] as usize,
+                state: State {
+                    direction,
+                    direction_count: if straight {
+                        candidate.state.direction_count + 1
+                    } else {
+                        1
+                    },
+                    pos: new_pos,
+                },
+            }));
+        }
+    }
+
+    None
+}
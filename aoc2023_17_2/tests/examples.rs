@@ -0,0 +1,24 @@
+#[test]
+fn example1() {
+    let answer = aoc2023_17_2::solve(include_str!("examples/example1.txt")).unwrap();
+    assert_eq!(answer, "94");
+}
+
+#[test]
+fn example2() {
+    let answer = aoc2023_17_2::solve(include_str!("examples/example2.txt")).unwrap();
+    assert_eq!(answer, "71");
+}
+
+#[test]
+fn part1_limits() {
+    use aoc2023_common::{with_params, Params};
+
+    let mut params = Params::default();
+    params.set("min_run", "1");
+    params.set("max_run", "3");
+    let answer = with_params(params, || {
+        aoc2023_17_2::solve(include_str!("examples/example1.txt"))
+    });
+    assert_eq!(answer.unwrap(), "102");
+}
@@ -0,0 +1,132 @@
+use std::io::BufRead;
+
+use anyhow::{Context, Error};
+use aoc2023_common::{lines, Answer, Direction, Grid, InputError, Line, Part, Point, Solution};
+use log::debug;
+
+pub struct Day18;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day18>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day18, R>(Part::One, reader)
+}
+
+pub struct Instruction {
+    direction: Direction,
+    steps: u8,
+}
+
+fn flood_fill(grid: &mut Grid<u8>, pos: (usize, usize)) {
+    let mut candidates = vec![pos];
+
+    while let Some((x, y)) = candidates.pop() {
+        if grid[(x, y)] != b'.' {
+            continue;
+        }
+
+        grid[(x, y)] = b'O';
+
+        candidates.extend(grid.neighbours4(x, y));
+    }
+}
+
+/// Parses a color written like `(#70c710)`.
+fn parse_color(line: &Line, color: &str) -> Result<u32, InputError> {
+    color
+        .strip_prefix("(#")
+        .and_then(|hex| hex.strip_suffix(')'))
+        .filter(|hex| hex.len() == 6)
+        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+        .ok_or_else(|| {
+            line.error_at(
+                color,
+                format!("invalid color {:?}, expected (#rrggbb)", color),
+            )
+        })
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Parsed = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut instructions = Vec::<Instruction>::new();
+
+        for line in lines(input) {
+            let mut parts = line.split(' ');
+            let direction = parts
+                .next()
+                .filter(|direction| !direction.is_empty())
+                .ok_or_else(|| line.error_here("missing direction"))?;
+            let direction = line.parse(direction)?;
+            let steps = parts
+                .next()
+                .ok_or_else(|| line.error_here("missing steps"))?;
+            let steps = line.parse::<u8>(steps)?;
+            let color = parts
+                .next()
+                .ok_or_else(|| line.error_here("missing color"))?;
+            parse_color(&line, color)?;
+
+            let instruction = Instruction { direction, steps };
+            instructions.push(instruction);
+        }
+
+        Ok(instructions)
+    }
+
+    fn part1(instructions: &Self::Parsed) -> Result<Answer, Error> {
+        let mut current = Point::default();
+        let mut top_left = current;
+        let mut bottom_right = current;
+
+        for instruction in instructions.iter() {
+            current += instruction.direction.offset() * i64::from(instruction.steps);
+            top_left = top_left.min(current);
+            bottom_right = bottom_right.max(current);
+        }
+
+        debug!("top_left {:?} bottom_right {:?}", top_left, bottom_right);
+
+        // add a border so it's easier to flood fill the un-dug space
+
+        let size = bottom_right - top_left + Point::new(3, 3);
+        let mut current = Point::new(1, 1) - top_left;
+
+        debug!("size {:?} initial {:?}", size, current);
+
+        let mut grid = Grid::new(size.x.try_into()?, size.y.try_into()?, b'.');
+
+        let pos = grid
+            .position_of(current)
+            .context("start outside the grid")?;
+        grid[pos] = b'#';
+
+        for instruction in instructions.iter() {
+            for _ in 0..instruction.steps {
+                current = current.step(instruction.direction);
+                let pos = grid.position_of(current).context("dug outside the grid")?;
+                grid[pos] = b'#';
+            }
+        }
+
+        flood_fill(&mut grid, (0, 0));
+
+        debug!("{}", grid);
+
+        let dug_or_inside_count = grid
+            .cells()
+            .iter()
+            .cloned()
+            .filter(|t| *t == b'#' || *t == b'.')
+            .count();
+
+        Ok(dug_or_inside_count.into())
+    }
+}
@@ -0,0 +1,18 @@
+use aoc2023_common::InputError;
+
+#[test]
+fn example() {
+    let answer = aoc2023_18::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "62");
+}
+
+#[test]
+fn compass_directions_are_rejected() {
+    let error = aoc2023_18::solve("R 6 (#70c710)\nN 6 (#70c710)\n").unwrap_err();
+    let error = error.downcast_ref::<InputError>().unwrap();
+    assert_eq!((error.line(), error.column()), (Some(2), Some(1)));
+    assert_eq!(
+        error.message(),
+        "invalid value \"N\": invalid direction \"N\""
+    );
+}
@@ -0,0 +1,290 @@
+use std::cmp::{max, min};
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{
+    lines, Answer, Direction, Grid, InputError, Line, Overflow, Part, Point, Solution, Strategy,
+};
+use log::{debug, trace};
+
+pub struct Day18;
+
+/// Solves part 2 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day18>(Part::Two, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day18, R>(Part::Two, reader)
+}
+
+struct FixedInstruction {
+    direction: Direction,
+    steps: u32,
+}
+
+pub struct Instruction {
+    color: u32,
+}
+
+/// The instructions, one per line, in the form the strategies take.
+pub struct DigPlan {
+    instructions: Vec<Instruction>,
+}
+
+fn unrolled_flood_fill(grid: &mut Grid<u8>, pos: (usize, usize)) {
+    let mut candidates = Vec::<(usize, usize)>::new();
+
+    candidates.push(pos);
+
+    while let Some((x, y)) = candidates.pop() {
+        if grid[(x, y)] != b'.' {
+            continue;
+        }
+
+        grid[(x, y)] = b'O';
+
+        candidates.extend(grid.neighbours4(x, y));
+    }
+}
+
+/// Parses a color written like `(#70c710)`.
+fn parse_color(line: &Line, color: &str) -> Result<u32, InputError> {
+    color
+        .strip_prefix("(#")
+        .and_then(|hex| hex.strip_suffix(')'))
+        .filter(|hex| hex.len() == 6)
+        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+        .ok_or_else(|| {
+            line.error_at(
+                color,
+                format!("invalid color {:?}, expected (#rrggbb)", color),
+            )
+        })
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Parsed = DigPlan;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut instructions = Vec::<Instruction>::new();
+
+        for line in lines(input) {
+            let mut parts = line.split(' ');
+            let direction = parts
+                .next()
+                .filter(|direction| !direction.is_empty())
+                .ok_or_else(|| line.error_here("missing direction"))?;
+            line.parse::<Direction>(direction)?;
+            let steps = parts
+                .next()
+                .ok_or_else(|| line.error_here("missing steps"))?;
+            line.parse::<u8>(steps)?;
+            let color_text = parts
+                .next()
+                .ok_or_else(|| line.error_here("missing color"))?;
+            let color = parse_color(&line, color_text)?;
+            if color & 0xf > 3 {
+                return Err(line
+                    .error_at(
+                        color_text,
+                        format!("invalid direction digit {:x}", color & 0xf),
+                    )
+                    .into());
+            }
+
+            let instruction = Instruction { color };
+            instructions.push(instruction);
+        }
+
+        Ok(DigPlan { instructions })
+    }
+
+    fn part2(plan: &Self::Parsed) -> Result<Answer, Error> {
+        dug_by_flood_fill(plan)
+    }
+
+    fn strategies(part: Part) -> Vec<Strategy<Self::Parsed>> {
+        match part {
+            Part::One => Vec::new(),
+            Part::Two => vec![
+                Strategy {
+                    name: "compressed flood fill",
+                    solve: dug_by_flood_fill,
+                },
+                Strategy {
+                    name: "shoelace",
+                    solve: dug_by_shoelace,
+                },
+            ],
+        }
+    }
+}
+
+/// The real instructions, hidden in the colors.
+fn decode(instructions: &[Instruction]) -> Vec<FixedInstruction> {
+    instructions
+        .iter()
+        .map(|i| {
+            let direction = match i.color & 0xf {
+                0 => Direction::Right,
+                1 => Direction::Down,
+                2 => Direction::Left,
+                3 => Direction::Up,
+                _ => unreachable!("directions are checked while parsing"),
+            };
+            let steps = i.color >> 4;
+            FixedInstruction { direction, steps }
+        })
+        .collect()
+}
+
+/// Digs the outline in a grid compressed to the rows and columns of its corners, flood fills
+/// the outside and adds up the areas the remaining slots stand for.
+fn dug_by_flood_fill(plan: &DigPlan) -> Result<Answer, Error> {
+    let instructions = decode(&plan.instructions);
+
+    for instruction in instructions.iter() {
+        trace!("{:?} {}", instruction.direction, instruction.steps);
+    }
+
+    let mut current = Point::default();
+
+    let mut boundary_xs = Vec::<i64>::new();
+    let mut boundary_ys = Vec::<i64>::new();
+
+    vec_set_insert(&mut boundary_xs, current.x);
+    vec_set_insert(&mut boundary_ys, current.y);
+
+    for instruction in instructions.iter() {
+        current += instruction.direction.offset() * i64::from(instruction.steps);
+        vec_set_insert(&mut boundary_xs, current.x);
+        vec_set_insert(&mut boundary_ys, current.y);
+    }
+
+    debug!(
+        "boundary_xs {:?} boundary_ys {:?}",
+        boundary_xs, boundary_ys
+    );
+
+    // odd slots are the boundaries themselves, even slots the space between them, with an
+    // extra slot on each side so it's easy to flood fill the un-dug space
+    let slots_x = boundary_xs.len() * 2 + 1;
+    let slots_y = boundary_ys.len() * 2 + 1;
+
+    let slot = |point: Point| -> Result<(usize, usize), Error> {
+        let Ok(i_x) = boundary_xs.binary_search(&point.x) else {
+            return Err(Error::msg("slot x not found"));
+        };
+        let Ok(i_y) = boundary_ys.binary_search(&point.y) else {
+            return Err(Error::msg("slot y not found"));
+        };
+        Ok((2 * i_x + 1, 2 * i_y + 1))
+    };
+
+    let mut grid = Grid::new(slots_x, slots_y, b'.');
+
+    let mut current = Point::default();
+
+    grid[slot(current)?] = b'#';
+
+    for instruction in instructions.iter() {
+        let next = current + instruction.direction.offset() * i64::from(instruction.steps);
+
+        let (start_slot_x, start_slot_y) = slot(current)?;
+        let (last_slot_x, last_slot_y) = slot(next)?;
+
+        for slot_y in min(start_slot_y, last_slot_y)..max(start_slot_y, last_slot_y) + 1 {
+            for slot_x in min(start_slot_x, last_slot_x)..max(start_slot_x, last_slot_x) + 1 {
+                grid[(slot_x, slot_y)] = b'#';
+            }
+        }
+
+        current = next;
+    }
+
+    debug!("before fill");
+    debug!("{}", grid);
+
+    unrolled_flood_fill(&mut grid, (0, 0));
+
+    debug!("after fill");
+    debug!("{}", grid);
+
+    let mut dug_or_surrounded = 0i64;
+
+    for (slot_x, slot_y) in grid.positions() {
+        let tile = grid[(slot_x, slot_y)];
+        if tile == b'O' {
+            continue;
+        }
+
+        let height = if slot_y % 2 == 1 {
+            1
+        } else {
+            boundary_ys[slot_y / 2] - boundary_ys[slot_y / 2 - 1] - 1
+        };
+        let width = if slot_x % 2 == 1 {
+            1
+        } else {
+            boundary_xs[slot_x / 2] - boundary_xs[slot_x / 2 - 1] - 1
+        };
+
+        let area = height.checked_mul(width).ok_or(Overflow)?;
+
+        trace!(
+            "slot_x {} slot_y {} height {} width {} area {} type {}",
+            slot_x,
+            slot_y,
+            height,
+            width,
+            area,
+            tile as char
+        );
+
+        dug_or_surrounded = dug_or_surrounded.checked_add(area).ok_or(Overflow)?;
+    }
+
+    Ok(dug_or_surrounded.into())
+}
+
+/// The outline's area by the shoelace formula, through the centres of the corner squares, plus
+/// the half of each boundary square outside that and the extra quarter squares at the four
+/// more outward corners than inward ones.
+fn dug_by_shoelace(plan: &DigPlan) -> Result<Answer, Error> {
+    let mut current = Point::default();
+    let mut twice_area = 0i64;
+    let mut perimeter = 0i64;
+
+    for instruction in decode(&plan.instructions) {
+        let next = current + instruction.direction.offset() * i64::from(instruction.steps);
+        let twice_triangle = current
+            .x
+            .checked_mul(next.y)
+            .zip(next.x.checked_mul(current.y))
+            .and_then(|(a, b)| a.checked_sub(b))
+            .ok_or(Overflow)?;
+        twice_area = twice_area.checked_add(twice_triangle).ok_or(Overflow)?;
+        perimeter += i64::from(instruction.steps);
+        current = next;
+    }
+
+    if current != Point::default() {
+        return Err(InputError::new("the trench doesn't return to where it started").into());
+    }
+
+    let area = twice_area.checked_abs().ok_or(Overflow)? / 2;
+    Ok(area.checked_add(perimeter / 2 + 1).ok_or(Overflow)?.into())
+}
+
+fn vec_set_insert<T>(vec: &mut Vec<T>, value: T)
+where
+    T: Ord,
+{
+    if let Err(pos) = vec.binary_search(&value) {
+        vec.insert(pos, value);
+    }
+}
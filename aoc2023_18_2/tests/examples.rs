@@ -0,0 +1,49 @@
+use aoc2023_common::InputError;
+
+#[test]
+fn example() {
+    let answer = aoc2023_18_2::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "952408144115");
+}
+
+#[test]
+fn malformed_input() {
+    let error = aoc2023_18_2::solve("R 6 (#70c710)\nD 5 (#0dc571\n").unwrap_err();
+    let error = error.downcast_ref::<InputError>().unwrap();
+    assert_eq!(error.day(), Some(18));
+    assert_eq!((error.line(), error.column()), (Some(2), Some(5)));
+    assert_eq!(
+        error.message(),
+        "invalid color \"(#0dc571\", expected (#rrggbb)"
+    );
+}
+
+#[test]
+fn compass_directions_are_rejected() {
+    let error = aoc2023_18_2::solve("R 6 (#70c710)\nN 6 (#70c710)\n").unwrap_err();
+    let error = error.downcast_ref::<InputError>().unwrap();
+    assert_eq!((error.line(), error.column()), (Some(2), Some(1)));
+    assert_eq!(
+        error.message(),
+        "invalid value \"N\": invalid direction \"N\""
+    );
+}
+
+#[test]
+fn strategies_agree() {
+    use aoc2023_18_2::Day18;
+    use aoc2023_common::{cross_check, Part};
+
+    let runs = cross_check::<Day18>(Part::Two, include_str!("examples/example.txt")).unwrap();
+    let answers = runs
+        .iter()
+        .map(|run| (run.name, run.answer.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        answers,
+        [
+            ("compressed flood fill", "952408144115"),
+            ("shoelace", "952408144115")
+        ]
+    );
+}
@@ -0,0 +1,240 @@
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, Answer, CheckedIterator, InputError, Line, Overflow, Solution};
+use log::trace;
+
+pub struct Day19;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day19>(aoc2023_common::Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day19, R>(aoc2023_common::Part::One, reader)
+}
+
+pub struct System {
+    parts: Vec<Part>,
+    workflows: HashMap<Vec<u8>, Workflow>,
+}
+
+#[derive(Default, Debug)]
+struct Part {
+    a: u64,
+    m: u64,
+    s: u64,
+    x: u64,
+}
+
+#[derive(Default, Debug)]
+struct Rule {
+    operator: u8,
+    property: u8,
+    target: Vec<u8>,
+    threshold: u64,
+}
+
+#[derive(Default, Debug)]
+struct Workflow {
+    rules: Vec<Rule>,
+    target: Vec<u8>,
+}
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Parsed = System;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut workflows = HashMap::<Vec<u8>, Workflow>::new();
+        let mut parts = Vec::<Part>::new();
+
+        let mut targets = Vec::new();
+        let mut workflows_over = false;
+
+        for line in lines(input) {
+            if line.is_empty() {
+                workflows_over = true;
+                continue;
+            }
+
+            if !workflows_over {
+                let (name, workflow) = parse_workflow(&line, &mut targets)?;
+                workflows.insert(name, workflow);
+            } else {
+                parts.push(parse_part(&line)?);
+            }
+        }
+
+        check_targets(&workflows, &targets)?;
+
+        Ok(System { parts, workflows })
+    }
+
+    fn part1(system: &Self::Parsed) -> Result<Answer, Error> {
+        let mut total_rating = 0u64;
+
+        for part in system.parts.iter() {
+            let workflow_result = run_workflows(&system.workflows, part)?;
+            if workflow_result == b"A" {
+                let rating = [part.a, part.m, part.s, part.x].into_iter().checked_sum()?;
+                total_rating = total_rating.checked_add(rating).ok_or(Overflow)?;
+            }
+        }
+
+        Ok(total_rating.into())
+    }
+}
+
+/// Parses a workflow like `px{a<2006:qkq,m>2090:A,rfg}`, adding the targets it names to
+/// `targets` so they can be checked once every workflow is known.
+fn parse_workflow<'a>(
+    line: &Line<'a>,
+    targets: &mut Vec<(Line<'a>, &'a str)>,
+) -> Result<(Vec<u8>, Workflow), InputError> {
+    let (name, rest) = line
+        .text()
+        .split_once('{')
+        .ok_or_else(|| line.error_here("missing open brace"))?;
+    let body = rest
+        .strip_suffix('}')
+        .ok_or_else(|| line.error_here("missing close brace"))?;
+
+    let mut workflow = Workflow::default();
+    let mut steps = body.split(',').peekable();
+    while let Some(step) = steps.next() {
+        trace!("workflow step {}", step);
+        if steps.peek().is_none() {
+            if step.is_empty() || step.contains(':') {
+                return Err(line.error_at(step, "workflow must end with a target"));
+            }
+            workflow.target = step.as_bytes().to_owned();
+            targets.push((*line, step));
+            break;
+        }
+        let (rule, target) = parse_rule(line, step)?;
+        trace!("rule {:?}", rule);
+        targets.push((*line, target));
+        workflow.rules.push(rule);
+    }
+
+    Ok((name.as_bytes().to_owned(), workflow))
+}
+
+/// Parses a rule like `a<2006:qkq`, returning it along with its target.
+fn parse_rule<'a>(line: &Line, step: &'a str) -> Result<(Rule, &'a str), InputError> {
+    let (condition, target) = step
+        .split_once(':')
+        .ok_or_else(|| line.error_at(step, "expected a rule like a<2006:qkq"))?;
+    let &[property, operator, ..] = condition.as_bytes() else {
+        return Err(line.error_at(step, "expected a rule like a<2006:qkq"));
+    };
+    if !b"xmas".contains(&property) {
+        return Err(line.error_at(
+            condition,
+            format!("invalid property {:?}", property as char),
+        ));
+    }
+    if operator != b'<' && operator != b'>' {
+        return Err(line.error_at(
+            &condition[1..],
+            format!("invalid operator {:?}", operator as char),
+        ));
+    }
+    let threshold = line.parse::<u64>(&condition[2..])?;
+
+    let rule = Rule {
+        operator,
+        property,
+        target: target.as_bytes().to_owned(),
+        threshold,
+    };
+    Ok((rule, target))
+}
+
+/// Checks that there is an `in` workflow and that every target is `A`, `R` or a workflow.
+fn check_targets(
+    workflows: &HashMap<Vec<u8>, Workflow>,
+    targets: &[(Line, &str)],
+) -> Result<(), InputError> {
+    if !workflows.contains_key(b"in".as_slice()) {
+        return Err(InputError::new("no workflow named in"));
+    }
+    for (line, target) in targets {
+        if !matches!(*target, "A" | "R") && !workflows.contains_key(target.as_bytes()) {
+            return Err(line.error_at(*target, format!("unknown workflow {:?}", target)));
+        }
+    }
+    Ok(())
+}
+
+/// Parses a part like `{x=787,m=2655,a=1222,s=2876}`.
+fn parse_part(line: &Line) -> Result<Part, InputError> {
+    let ratings = line
+        .strip_prefix('{')
+        .and_then(|rest| rest.strip_suffix('}'))
+        .ok_or_else(|| line.error_here("expected a part like {x=787,m=2655,a=1222,s=2876}"))?;
+    let mut ratings = ratings.split(',');
+    let mut rating = |property: &str| {
+        let rating = ratings
+            .next()
+            .ok_or_else(|| line.error_here(format!("missing {}", property)))?;
+        let value = rating
+            .strip_prefix(property)
+            .and_then(|rest| rest.strip_prefix('='))
+            .ok_or_else(|| line.error_at(rating, format!("expected {}=<rating>", property)))?;
+        line.parse::<u64>(value)
+    };
+    let x = rating("x")?;
+    let m = rating("m")?;
+    let a = rating("a")?;
+    let s = rating("s")?;
+
+    Ok(Part { a, m, s, x })
+}
+
+/// Runs `part` through the workflows from `in`, returning where it ends up (`A` or `R`).
+fn run_workflows(
+    workflows: &HashMap<Vec<u8>, Workflow>,
+    part: &Part,
+) -> Result<Vec<u8>, InputError> {
+    let mut current_name = b"in".as_slice();
+    let mut visited = HashSet::new();
+    while let Some(workflow) = workflows.get(current_name) {
+        if !visited.insert(current_name) {
+            return Err(InputError::new(format!(
+                "workflow {} is reached twice by {:?}",
+                String::from_utf8_lossy(current_name),
+                part
+            )));
+        }
+        let mut rule_matched = false;
+        for rule in workflow.rules.iter() {
+            let value = match rule.property {
+                b'a' => part.a,
+                b'm' => part.m,
+                b's' => part.s,
+                b'x' => part.x,
+                _ => unreachable!("properties are checked while parsing"),
+            };
+            let op = match rule.operator {
+                b'<' => u64::lt,
+                b'>' => u64::gt,
+                _ => unreachable!("operators are checked while parsing"),
+            };
+            if op(&value, &rule.threshold) {
+                current_name = &rule.target;
+                rule_matched = true;
+                break;
+            }
+        }
+        if !rule_matched {
+            current_name = &workflow.target;
+        }
+    }
+    Ok(current_name.to_vec())
+}
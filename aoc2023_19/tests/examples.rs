@@ -0,0 +1,41 @@
+use aoc2023_common::InputError;
+
+#[test]
+fn example() {
+    let answer = aoc2023_19::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "19114");
+}
+
+#[test]
+fn malformed_input() {
+    let error = aoc2023_19::solve("in{a<2006:qkq,z>2:A,rfg}\n\n{x=787,m=2655,a=1222,s=2876}\n")
+        .unwrap_err();
+    let error = error.downcast_ref::<InputError>().unwrap();
+    assert_eq!(error.day(), Some(19));
+    assert_eq!((error.line(), error.column()), (Some(1), Some(15)));
+    assert_eq!(error.message(), "invalid property 'z'");
+}
+
+#[test]
+fn overflowing_ratings() {
+    use aoc2023_common::Overflow;
+
+    let input = format!("in{{A}}\n\n{{x={},m=1,a=0,s=0}}\n", u64::MAX);
+    let error = aoc2023_19::solve(&input).unwrap_err();
+    assert!(error.downcast_ref::<Overflow>().is_some(), "{:#}", error);
+}
+
+#[test]
+fn unknown_target() {
+    let error = aoc2023_19::solve("in{a<5:qq,R}\n\n{x=1,m=1,a=1,s=1}\n").unwrap_err();
+    let error = error.downcast_ref::<InputError>().unwrap();
+    assert_eq!((error.line(), error.column()), (Some(1), Some(8)));
+    assert_eq!(error.message(), "unknown workflow \"qq\"");
+}
+
+#[test]
+fn looping_workflow() {
+    let error = aoc2023_19::solve("in{a<5:in,R}\n\n{x=1,m=1,a=1,s=1}\n").unwrap_err();
+    let error = error.downcast_ref::<InputError>().unwrap();
+    assert!(error.message().starts_with("workflow in is reached twice"));
+}
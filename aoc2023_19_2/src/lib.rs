@@ -0,0 +1,336 @@
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{
+    lines, param, Answer, CaptureSink, CheckedIterator, InputError, Line, Overflow, Param,
+    Progress, Solution,
+};
+use log::{debug, trace};
+use rayon::prelude::*;
+
+pub struct Day19;
+
+/// Solves part 2 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day19>(aoc2023_common::Part::Two, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day19, R>(aoc2023_common::Part::Two, reader)
+}
+
+#[derive(Default, Debug)]
+struct Part {
+    a: u16,
+    m: u16,
+    s: u16,
+    x: u16,
+}
+
+#[derive(Default, Debug)]
+struct Rule {
+    operator: u8,
+    property: u8,
+    target: Vec<u8>,
+    threshold: u16,
+}
+
+#[derive(Default, Debug)]
+pub struct Workflow {
+    rules: Vec<Rule>,
+    target: Vec<u8>,
+}
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "max_rating",
+        default: "4000",
+        help: "highest rating of each category, from 1",
+    }];
+
+    type Parsed = HashMap<Vec<u8>, Workflow>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut workflows = HashMap::<Vec<u8>, Workflow>::new();
+
+        let mut targets = Vec::new();
+        let mut workflows_over = false;
+
+        for line in lines(input) {
+            if line.is_empty() {
+                workflows_over = true;
+                continue;
+            }
+
+            if !workflows_over {
+                let (name, workflow) = parse_workflow(&line, &mut targets)?;
+                workflows.insert(name, workflow);
+            }
+        }
+
+        check_targets(&workflows, &targets)?;
+
+        Ok(workflows)
+    }
+
+    fn part2(workflows: &Self::Parsed) -> Result<Answer, Error> {
+        let max_rating = param::<Self, u16>("max_rating")?;
+        let mut boundaries_a = Vec::<u16>::new();
+        let mut boundaries_m = Vec::<u16>::new();
+        let mut boundaries_s = Vec::<u16>::new();
+        let mut boundaries_x = Vec::<u16>::new();
+
+        for workflow in workflows.values() {
+            for rule in workflow.rules.iter() {
+                vec_set_insert(
+                    match rule.property {
+                        b'a' => &mut boundaries_a,
+                        b'm' => &mut boundaries_m,
+                        b's' => &mut boundaries_s,
+                        b'x' => &mut boundaries_x,
+                        _ => unreachable!("properties are checked while parsing"),
+                    },
+                    // make each boundary value be the last number in that range
+                    rule.threshold - if rule.operator == b'<' { 1 } else { 0 },
+                );
+            }
+        }
+
+        for boundaries in [
+            &mut boundaries_a,
+            &mut boundaries_m,
+            &mut boundaries_s,
+            &mut boundaries_x,
+        ] {
+            boundaries.retain(|boundary| *boundary < max_rating);
+            boundaries.push(max_rating);
+        }
+
+        debug!("boundaries_a {} {:?}", boundaries_a.len(), boundaries_a);
+        debug!("boundaries_m {} {:?}", boundaries_m.len(), boundaries_m);
+        debug!("boundaries_s {} {:?}", boundaries_s.len(), boundaries_s);
+        debug!("boundaries_x {} {:?}", boundaries_x.len(), boundaries_x);
+
+        let slice = (boundaries_s.len() * boundaries_x.len()) as u64;
+        let progress = Progress::new(
+            module_path!(),
+            Some(boundaries_a.len() as u64 * boundaries_m.len() as u64 * slice),
+        );
+        // rayon runs the slices on its own threads, so send their records to this one's capture
+        let sink = CaptureSink::current();
+        let total_combos: u64 = boundaries_a
+            .par_iter()
+            .enumerate()
+            .map(|(i_a, a)| -> Result<u64, Error> {
+                let _entered = sink.enter();
+                let mut a_combos = 0u64;
+                for (i_m, m) in boundaries_m.iter().enumerate() {
+                    progress.advance(slice)?;
+                    for (i_s, s) in boundaries_s.iter().enumerate() {
+                        for (i_x, x) in boundaries_x.iter().enumerate() {
+                            let last_target = run_workflows(
+                                workflows,
+                                &Part {
+                                    a: *a,
+                                    m: *m,
+                                    s: *s,
+                                    x: *x,
+                                },
+                            )?;
+                            if last_target != b"A" {
+                                continue;
+                            }
+
+                            let a_before = if i_a == 0 {
+                                0
+                            } else {
+                                boundaries_a[i_a - 1] as u64
+                            };
+                            let m_before = if i_m == 0 {
+                                0
+                            } else {
+                                boundaries_m[i_m - 1] as u64
+                            };
+                            let s_before = if i_s == 0 {
+                                0
+                            } else {
+                                boundaries_s[i_s - 1] as u64
+                            };
+                            let x_before = if i_x == 0 {
+                                0
+                            } else {
+                                boundaries_x[i_x - 1] as u64
+                            };
+
+                            let combos = [
+                                *a as u64 - a_before,
+                                *m as u64 - m_before,
+                                *s as u64 - s_before,
+                                *x as u64 - x_before,
+                            ]
+                            .into_iter()
+                            .checked_product()?;
+
+                            trace!(
+                                "combos {} a ({}, {}] m ({}, {}] s ({}, {}] x ({}, {}]",
+                                combos,
+                                a_before,
+                                a,
+                                m_before,
+                                m,
+                                s_before,
+                                s,
+                                x_before,
+                                x
+                            );
+
+                            a_combos = a_combos.checked_add(combos).ok_or(Overflow)?;
+                        }
+                    }
+                }
+                Ok(a_combos)
+            })
+            .try_reduce(|| 0, |a, b| Ok(a.checked_add(b).ok_or(Overflow)?))?;
+
+        Ok(total_combos.into())
+    }
+}
+
+/// Parses a workflow like `px{a<2006:qkq,m>2090:A,rfg}`, adding the targets it names to
+/// `targets` so they can be checked once every workflow is known.
+fn parse_workflow<'a>(
+    line: &Line<'a>,
+    targets: &mut Vec<(Line<'a>, &'a str)>,
+) -> Result<(Vec<u8>, Workflow), InputError> {
+    let (name, rest) = line
+        .text()
+        .split_once('{')
+        .ok_or_else(|| line.error_here("missing open brace"))?;
+    let body = rest
+        .strip_suffix('}')
+        .ok_or_else(|| line.error_here("missing close brace"))?;
+
+    let mut workflow = Workflow::default();
+    let mut steps = body.split(',').peekable();
+    while let Some(step) = steps.next() {
+        trace!("workflow step {}", step);
+        if steps.peek().is_none() {
+            if step.is_empty() || step.contains(':') {
+                return Err(line.error_at(step, "workflow must end with a target"));
+            }
+            workflow.target = step.as_bytes().to_owned();
+            targets.push((*line, step));
+            break;
+        }
+        let (rule, target) = parse_rule(line, step)?;
+        trace!("rule {:?}", rule);
+        targets.push((*line, target));
+        workflow.rules.push(rule);
+    }
+
+    Ok((name.as_bytes().to_owned(), workflow))
+}
+
+/// Parses a rule like `a<2006:qkq`, returning it along with its target.
+fn parse_rule<'a>(line: &Line, step: &'a str) -> Result<(Rule, &'a str), InputError> {
+    let (condition, target) = step
+        .split_once(':')
+        .ok_or_else(|| line.error_at(step, "expected a rule like a<2006:qkq"))?;
+    let &[property, operator, ..] = condition.as_bytes() else {
+        return Err(line.error_at(step, "expected a rule like a<2006:qkq"));
+    };
+    if !b"xmas".contains(&property) {
+        return Err(line.error_at(
+            condition,
+            format!("invalid property {:?}", property as char),
+        ));
+    }
+    if operator != b'<' && operator != b'>' {
+        return Err(line.error_at(
+            &condition[1..],
+            format!("invalid operator {:?}", operator as char),
+        ));
+    }
+    let threshold = line.parse::<u16>(&condition[2..])?;
+    if operator == b'<' && threshold == 0 {
+        return Err(line.error_at(condition, "no rating is below 0"));
+    }
+
+    let rule = Rule {
+        operator,
+        property,
+        target: target.as_bytes().to_owned(),
+        threshold,
+    };
+    Ok((rule, target))
+}
+
+/// Checks that there is an `in` workflow and that every target is `A`, `R` or a workflow.
+fn check_targets(
+    workflows: &HashMap<Vec<u8>, Workflow>,
+    targets: &[(Line, &str)],
+) -> Result<(), InputError> {
+    if !workflows.contains_key(b"in".as_slice()) {
+        return Err(InputError::new("no workflow named in"));
+    }
+    for (line, target) in targets {
+        if !matches!(*target, "A" | "R") && !workflows.contains_key(target.as_bytes()) {
+            return Err(line.error_at(*target, format!("unknown workflow {:?}", target)));
+        }
+    }
+    Ok(())
+}
+
+/// Runs `part` through the workflows from `in`, returning where it ends up (`A` or `R`).
+fn run_workflows(
+    workflows: &HashMap<Vec<u8>, Workflow>,
+    part: &Part,
+) -> Result<Vec<u8>, InputError> {
+    let mut current_name = b"in".as_slice();
+    let mut visited = HashSet::new();
+    while let Some(workflow) = workflows.get(current_name) {
+        if !visited.insert(current_name) {
+            return Err(InputError::new(format!(
+                "workflow {} is reached twice by {:?}",
+                String::from_utf8_lossy(current_name),
+                part
+            )));
+        }
+        let mut rule_matched = false;
+        for rule in workflow.rules.iter() {
+            let value = match rule.property {
+                b'a' => part.a,
+                b'm' => part.m,
+                b's' => part.s,
+                b'x' => part.x,
+                _ => unreachable!("properties are checked while parsing"),
+            };
+            let op = match rule.operator {
+                b'<' => u16::lt,
+                b'>' => u16::gt,
+                _ => unreachable!("operators are checked while parsing"),
+            };
+            if op(&value, &rule.threshold) {
+                current_name = &rule.target;
+                rule_matched = true;
+                break;
+            }
+        }
+        if !rule_matched {
+            current_name = &workflow.target;
+        }
+    }
+    Ok(current_name.to_vec())
+}
+
+fn vec_set_insert<T>(vec: &mut Vec<T>, value: T)
+where
+    T: Ord,
+{
+    if let Err(pos) = vec.binary_search(&value) {
+        vec.insert(pos, value);
+    }
+}
@@ -0,0 +1,29 @@
+#[test]
+fn example() {
+    let answer = aoc2023_19_2::solve(include_str!("examples/example.txt")).unwrap();
+    assert_eq!(answer, "167409079868000");
+}
+
+#[test]
+fn parallel_diagnostics_are_captured() {
+    use aoc2023_common::{capture, init_logging};
+
+    init_logging("aoc2023_19_2=trace".parse().unwrap());
+    let (answer, diagnostics) =
+        capture(|| aoc2023_19_2::solve(include_str!("examples/example.txt")));
+    assert_eq!(answer.unwrap(), "167409079868000");
+    assert!(diagnostics
+        .iter()
+        .any(|d| d.message.starts_with("boundaries_a ")));
+    assert!(diagnostics.iter().any(|d| d.message.starts_with("combos ")));
+}
+
+#[test]
+fn looping_workflow() {
+    let error = aoc2023_19_2::solve("in{a<5:px,R}\npx{m>10:A,in}\n\n").unwrap_err();
+    assert!(
+        error.to_string().contains("workflow in is reached twice"),
+        "{}",
+        error
+    );
+}
@@ -0,0 +1,213 @@
+use std::collections::{HashMap, VecDeque};
+use std::io::BufRead;
+
+use anyhow::Error;
+use aoc2023_common::{lines, Answer, Interrupted, Overflow, Part, Progress, Solution};
+
+pub struct Day20;
+
+/// Solves part 1 for a whole puzzle input.
+pub fn solve(input: &str) -> Result<String, Error> {
+    aoc2023_common::solve::<Day20>(Part::One, input)
+}
+
+/// Like [`solve`], reading the puzzle input from `reader` first.
+pub fn solve_reader<R: BufRead>(reader: R) -> Result<String, Error> {
+    aoc2023_common::solve_reader::<Day20, R>(Part::One, reader)
+}
+
+pub struct Modules {
+    broadcaster_outputs: Vec<String>,
+    conjunction_inputs: HashMap<String, HashMap<String, PulseType>>, // last input
+    conjunction_outputs: HashMap<String, Vec<String>>,
+    flipflop_outputs: HashMap<String, Vec<String>>,
+    flipflop_states: HashMap<String, PulseType>, // next output
+}
+
+struct Pulse {
+    dst: String,
+    pulse_type: PulseType,
+    src: String,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Default)]
+pub enum PulseType {
+    #[default]
+    Low,
+    High,
+}
+
+impl PulseType {
+    fn flip(&self) -> Self {
+        match self {
+            PulseType::Low => PulseType::High,
+            PulseType::High => PulseType::Low,
+        }
+    }
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Parsed = Modules;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let mut broadcaster_outputs = Vec::<String>::new();
+        let mut conjunction_outputs = HashMap::<String, Vec<String>>::new();
+        let mut conjunction_inputs = HashMap::<String, HashMap<String, PulseType>>::new(); // last input
+        let mut flipflop_outputs = HashMap::<String, Vec<String>>::new();
+        let mut flipflop_states = HashMap::<String, PulseType>::new(); // next output
+
+        for line in lines(input) {
+            let Some((module_name, module_output)) = line.split_once(" -> ") else {
+                return Err(line.error_here("missing separator \" -> \"").into());
+            };
+            if module_name == "broadcaster" {
+                broadcaster_outputs = module_output.split(", ").map(Into::into).collect();
+                for output in broadcaster_outputs.iter() {
+                    conjunction_inputs
+                        .entry(output.clone())
+                        .or_default()
+                        .insert("broadcaster".to_owned(), PulseType::Low);
+                }
+            } else if let Some(name) = module_name.strip_prefix('%') {
+                let outputs = module_output
+                    .split(", ")
+                    .map(Into::into)
+                    .collect::<Vec<String>>();
+                for output in outputs.iter() {
+                    conjunction_inputs
+                        .entry(output.to_owned())
+                        .or_default()
+                        .insert(name.to_owned(), PulseType::Low);
+                }
+                flipflop_outputs.insert(name.to_owned(), outputs);
+                flipflop_states.insert(name.to_owned(), PulseType::High);
+            } else if let Some(name) = module_name.strip_prefix('&') {
+                let outputs = module_output
+                    .split(", ")
+                    .map(Into::into)
+                    .collect::<Vec<String>>();
+                for output in outputs.iter() {
+                    conjunction_inputs
+                        .entry(output.to_owned())
+                        .or_default()
+                        .insert(name.to_owned(), PulseType::Low);
+                }
+                conjunction_outputs.insert(name.to_owned(), outputs);
+            } else {
+                return Err(line
+                    .error_at(
+                        module_name,
+                        format!(
+                            "invalid module {:?}, expected broadcaster, %name or &name",
+                            module_name
+                        ),
+                    )
+                    .into());
+            }
+        }
+
+        Ok(Modules {
+            broadcaster_outputs,
+            conjunction_inputs,
+            conjunction_outputs,
+            flipflop_outputs,
+            flipflop_states,
+        })
+    }
+
+    fn part1(modules: &Self::Parsed) -> Result<Answer, Error> {
+        let mut conjunction_inputs = modules.conjunction_inputs.clone();
+        let mut flipflop_states = modules.flipflop_states.clone();
+
+        let mut total_low = 0;
+        let mut total_high = 0;
+
+        // a single press can keep sending pulses forever, so tick once per pulse
+        let progress = Progress::new(module_path!(), None);
+        for _ in 0..1000 {
+            let (low, high) = push_button(
+                &modules.broadcaster_outputs,
+                &modules.conjunction_outputs,
+                &modules.flipflop_outputs,
+                &mut conjunction_inputs,
+                &mut flipflop_states,
+                &progress,
+            )?;
+            total_low += low;
+            total_high += high;
+        }
+
+        let product = total_low.checked_mul(total_high).ok_or(Overflow)?;
+        Ok(product.into())
+    }
+}
+
+fn push_button(
+    broadcaster_outputs: &[String],
+    conjunction_outputs: &HashMap<String, Vec<String>>,
+    flipflop_outputs: &HashMap<String, Vec<String>>,
+    conjunction_inputs: &mut HashMap<String, HashMap<String, PulseType>>,
+    flipflop_states: &mut HashMap<String, PulseType>,
+    progress: &Progress,
+) -> Result<(u64, u64), Interrupted> {
+    let mut low = 0;
+    let mut high = 0;
+    let mut pulses = VecDeque::<Pulse>::new();
+
+    pulses.push_back(Pulse {
+        dst: "broadcaster".to_owned(),
+        pulse_type: PulseType::Low,
+        src: "button".to_owned(),
+    });
+
+    while let Some(pulse) = pulses.pop_front() {
+        progress.tick()?;
+        match pulse.pulse_type {
+            PulseType::Low => low += 1,
+            PulseType::High => high += 1,
+        }
+
+        if pulse.dst == "broadcaster" {
+            for dst in broadcaster_outputs.iter() {
+                pulses.push_back(Pulse {
+                    dst: dst.to_owned(),
+                    pulse_type: pulse.pulse_type,
+                    src: "broadcaster".to_owned(),
+                });
+            }
+        } else {
+            if let Some(state) = flipflop_states.get_mut(&pulse.dst) {
+                if pulse.pulse_type == PulseType::Low {
+                    for dst in flipflop_outputs.get(&pulse.dst).unwrap().iter() {
+                        pulses.push_back(Pulse {
+                            dst: dst.to_owned(),
+                            pulse_type: *state,
+                            src: pulse.dst.clone(),
+                        });
+                    }
+                    *state = state.flip();
+                }
+            } else if let Some(state) = conjunction_inputs.get_mut(&pulse.dst) {
+                state.insert(pulse.src, pulse.pulse_type);
+                let output_type = if state.values().all(|v| *v == PulseType::High) {
+                    PulseType::Low
+                } else {
+                    PulseType::High
+                };
+                if let Some(dsts) = conjunction_outputs.get(&pulse.dst) {
+                    for dst in dsts {
+                        pulses.push_back(Pulse {
+                            dst: dst.to_owned(),
+                            pulse_type: output_type,
+                            src: pulse.dst.clone(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    Ok((low, high))
+}
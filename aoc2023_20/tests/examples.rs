@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use aoc2023_common::{with_budget, Budget, InputError, Interrupted};
+
+#[test]
+fn example1() {
+    let answer = aoc2023_20::solve(include_str!("examples/example1.txt")).unwrap();
+    assert_eq!(answer, "32000000");
+}
+
+#[test]
+fn example2() {
+    let answer = aoc2023_20::solve(include_str!("examples/example2.txt")).unwrap();
+    assert_eq!(answer, "11687500");
+}
+
+#[test]
+fn malformed_input() {
+    let error = aoc2023_20::solve("broadcaster -> a\n%a -> b\nb -> a\n").unwrap_err();
+    let error = error.downcast_ref::<InputError>().unwrap();
+    assert_eq!(error.day(), Some(20));
+    assert_eq!((error.line(), error.column()), (Some(3), Some(1)));
+    assert_eq!(
+        error.message(),
+        "invalid module \"b\", expected broadcaster, %name or &name"
+    );
+}
+
+#[test]
+fn press_that_never_settles() {
+    // a conjunction feeding itself keeps pulsing within the first press
+    let error = with_budget(Budget::timeout(Duration::from_millis(100)), || {
+        aoc2023_20::solve("broadcaster -> a\n&a -> a\n")
+    })
+    .unwrap_err();
+    assert!(error.downcast_ref::<Interrupted>().unwrap().timed_out());
+}
@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use aoc2023_common::{with_budget, Budget, Interrupted};
+
+// the puzzle has no part 2 example, so this uses a two bit counter whose high bit only sends a
+// low pulse to rx on the fourth press
+#[test]
+fn counter() {
+    let answer = aoc2023_20_2::solve(include_str!("examples/counter.txt")).unwrap();
+    assert_eq!(answer, "4");
+}
+
+#[test]
+fn press_that_never_settles() {
+    let error = with_budget(Budget::timeout(Duration::from_millis(100)), || {
+        aoc2023_20_2::solve("broadcaster -> a\n&a -> a, rx\n")
+    })
+    .unwrap_err();
+    assert!(error.downcast_ref::<Interrupted>().unwrap().timed_out());
+}
+
+#[test]
+fn other_targets() {
+    use aoc2023_common::{with_params, Params};
+
+    let mut params = Params::default();
+    params.set("target", "b");
+    let answer = with_params(params, || {
+        aoc2023_20_2::solve(include_str!("examples/counter.txt"))
+    });
+    assert_eq!(answer.unwrap(), "2");
+
+    let mut params = Params::default();
+    params.set("target", "nope");
+    let error = with_params(params, || {
+        aoc2023_20_2::solve(include_str!("examples/counter.txt"))
+    })
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unknown target \"nope\", no module sends pulses to it"
+    );
+}
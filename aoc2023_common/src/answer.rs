@@ -0,0 +1,133 @@
+use std::{error, fmt};
+
+use num_bigint::{BigInt, BigUint};
+use num_traits::{CheckedAdd, CheckedMul, One, Zero};
+
+/// A part's answer: an integer of any size or sign, or text for the puzzles that want it.
+///
+/// Solvers build one with `.into()` from whatever they computed the answer in, so the answer
+/// type doesn't have to be chosen up front, and the runner only ever sees its text.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    /// Past what `i128` and `u128` hold.
+    Big(BigInt),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsigned(value) => value.fmt(f),
+            Self::Signed(value) => value.fmt(f),
+            Self::Big(value) => value.fmt(f),
+            Self::Text(value) => value.fmt(f),
+        }
+    }
+}
+
+macro_rules! from_integers {
+    ($variant:ident, $wide:ty, $($ty:ty),+) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value as $wide)
+                }
+            }
+        )+
+    };
+}
+
+from_integers!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+from_integers!(Signed, i128, i8, i16, i32, i64, i128, isize);
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        Self::Big(value)
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(value: BigUint) -> Self {
+        Self::Big(value.into())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_owned())
+    }
+}
+
+/// An answer, or a step on the way to one, too big for the type a solver computes it in.
+///
+/// Solvers use the `checked_*` methods of their integers, or [`CheckedIterator`], and return
+/// this rather than letting the arithmetic wrap into a wrong answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("arithmetic overflow")
+    }
+}
+
+impl error::Error for Overflow {}
+
+/// [`Iterator::sum`] and [`Iterator::product`], failing with [`Overflow`] instead of
+/// wrapping.
+pub trait CheckedIterator: Iterator + Sized {
+    fn checked_sum(mut self) -> Result<Self::Item, Overflow>
+    where
+        Self::Item: CheckedAdd + Zero,
+    {
+        self.try_fold(Self::Item::zero(), |total, value| {
+            total.checked_add(&value).ok_or(Overflow)
+        })
+    }
+
+    fn checked_product(mut self) -> Result<Self::Item, Overflow>
+    where
+        Self::Item: CheckedMul + One,
+    {
+        self.try_fold(Self::Item::one(), |total, value| {
+            total.checked_mul(&value).ok_or(Overflow)
+        })
+    }
+}
+
+impl<I: Iterator> CheckedIterator for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_display_as_their_values() {
+        assert_eq!(Answer::from(42u8).to_string(), "42");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from(-7i64).to_string(), "-7");
+        let big = BigUint::from(u128::MAX) * 10u8;
+        assert_eq!(
+            Answer::from(big).to_string(),
+            "3402823669209384634633746074317682114550"
+        );
+        assert_eq!(Answer::from("qrs").to_string(), "qrs");
+    }
+
+    #[test]
+    fn checked_sums_and_products() {
+        assert_eq!([1u32, 2, 3].into_iter().checked_sum(), Ok(6));
+        assert_eq!([u32::MAX, 1].into_iter().checked_sum(), Err(Overflow));
+        assert_eq!([2i8, -3, 4].into_iter().checked_product(), Ok(-24));
+        assert_eq!([16i8, 8].into_iter().checked_product(), Err(Overflow));
+        assert_eq!(std::iter::empty::<u64>().checked_product(), Ok(1));
+    }
+}
@@ -0,0 +1,290 @@
+use std::cell::RefCell;
+use std::error;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::info;
+
+thread_local! {
+    static BUDGET: RefCell<Budget> = RefCell::new(Budget::unlimited());
+}
+
+/// How long a solver may run, and a switch for stopping it early.
+///
+/// The runner installs one around a solver with [`with_budget`]; long loops pick it up through
+/// [`Progress`], which they tick once per iteration. Clones share the switch, so whoever started
+/// the solver can [`cancel`](Budget::cancel) it from another thread.
+#[derive(Clone, Debug)]
+pub struct Budget {
+    deadline: Option<Instant>,
+    cancelled: Arc<AtomicBool>,
+    report_every: Duration,
+}
+
+impl Budget {
+    /// No time limit, reporting progress every second.
+    pub fn unlimited() -> Self {
+        Self {
+            deadline: None,
+            cancelled: Arc::new(AtomicBool::new(false)),
+            report_every: Duration::from_secs(1),
+        }
+    }
+
+    /// A limit of `timeout` from now.
+    pub fn timeout(timeout: Duration) -> Self {
+        Self {
+            deadline: Some(Instant::now() + timeout),
+            ..Self::unlimited()
+        }
+    }
+
+    /// Sets how often long loops log their progress.
+    pub fn report_every(mut self, interval: Duration) -> Self {
+        self.report_every = interval;
+        self
+    }
+
+    /// Asks every loop running under this budget to stop at its next iteration.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    fn exhausted(&self) -> Option<Reason> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            Some(Reason::Cancelled)
+        } else if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            Some(Reason::TimedOut)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self::unlimited()
+    }
+}
+
+/// Runs `f` with `budget` as the budget of any [`Progress`] it creates on this thread.
+pub fn with_budget<T, F>(budget: Budget, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let _restore = RestoreBudget(Some(BUDGET.replace(budget)));
+    f()
+}
+
+/// Puts back the budget [`with_budget`] replaced, even if its closure panics.
+struct RestoreBudget(Option<Budget>);
+
+impl Drop for RestoreBudget {
+    fn drop(&mut self) {
+        if let Some(outer) = self.0.take() {
+            BUDGET.set(outer);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Reason {
+    Cancelled,
+    TimedOut,
+}
+
+/// A loop stopped before finishing because its [`Budget`] ran out or was cancelled.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Interrupted {
+    reason: Reason,
+    iterations: u64,
+}
+
+impl Interrupted {
+    pub fn iterations(&self) -> u64 {
+        self.iterations
+    }
+
+    pub fn timed_out(&self) -> bool {
+        self.reason == Reason::TimedOut
+    }
+}
+
+impl fmt::Display for Interrupted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self.reason {
+            Reason::Cancelled => "cancelled",
+            Reason::TimedOut => "timed out",
+        };
+        write!(f, "{} after {} iterations", reason, self.iterations)
+    }
+}
+
+impl error::Error for Interrupted {}
+
+/// Tracks a long loop: counts its iterations, logs how it's going now and then, and stops it
+/// when the [`Budget`] it was created under runs out.
+///
+/// It can be shared between threads, so a parallel loop can tick the same one from every
+/// worker. Progress is logged at info level under `target` by whichever thread is due to.
+pub struct Progress {
+    target: &'static str,
+    total: Option<u64>,
+    budget: Budget,
+    started: Instant,
+    iterations: AtomicU64,
+    /// When the next report is due, in nanoseconds since `started`.
+    next_report: AtomicU64,
+}
+
+impl Progress {
+    /// Starts tracking a loop of `total` iterations, if that's known, under this thread's
+    /// budget. `target` is the log target to report under, usually `module_path!()`.
+    pub fn new(target: &'static str, total: Option<u64>) -> Self {
+        let budget = BUDGET.with_borrow(Clone::clone);
+        Self {
+            target,
+            total,
+            started: Instant::now(),
+            iterations: AtomicU64::new(0),
+            next_report: AtomicU64::new(budget.report_every.as_nanos() as u64),
+            budget,
+        }
+    }
+
+    /// Counts one iteration; see [`Progress::advance`].
+    pub fn tick(&self) -> Result<(), Interrupted> {
+        self.advance(1)
+    }
+
+    /// Counts `iterations` more, reports progress if it's time to, and fails if the loop
+    /// should stop.
+    pub fn advance(&self, iterations: u64) -> Result<(), Interrupted> {
+        let done = self.iterations.fetch_add(iterations, Ordering::Relaxed) + iterations;
+        if let Some(reason) = self.budget.exhausted() {
+            return Err(Interrupted {
+                reason,
+                iterations: done,
+            });
+        }
+
+        let elapsed = self.started.elapsed();
+        let due = self.next_report.load(Ordering::Relaxed);
+        let next = (elapsed + self.budget.report_every).as_nanos() as u64;
+        if elapsed.as_nanos() as u64 >= due
+            && self
+                .next_report
+                .compare_exchange(due, next, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+        {
+            info!(target: self.target, "{}", self.report(done, elapsed));
+        }
+        Ok(())
+    }
+
+    pub fn iterations(&self) -> u64 {
+        self.iterations.load(Ordering::Relaxed)
+    }
+
+    fn report(&self, done: u64, elapsed: Duration) -> String {
+        let rate = done as f64 / elapsed.as_secs_f64().max(f64::EPSILON);
+        let mut report = format!("{} iterations, {:.0}/s", done, rate);
+        if let Some(total) = self.total {
+            let left = total.saturating_sub(done) as f64 / rate.max(f64::EPSILON);
+            report += &format!(
+                ", {:.1}% of {}, ETA {:.0?}",
+                done as f64 * 100.0 / total.max(1) as f64,
+                total,
+                Duration::from_secs_f64(left.min(1e9))
+            );
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::logging::{capture, init_logging};
+    use crate::panic::catch_panic;
+
+    #[test]
+    fn unlimited_never_stops() {
+        let progress = Progress::new("test", None);
+        for _ in 0..1000 {
+            progress.tick().unwrap();
+        }
+        assert_eq!(progress.iterations(), 1000);
+    }
+
+    #[test]
+    fn timeout_stops_with_iteration_count() {
+        let error = with_budget(Budget::timeout(Duration::from_millis(20)), || {
+            let progress = Progress::new("test", None);
+            loop {
+                if let Err(e) = progress.tick() {
+                    break e;
+                }
+            }
+        });
+        assert!(error.timed_out());
+        assert!(error.iterations() > 0);
+        assert_eq!(
+            error.to_string(),
+            format!("timed out after {} iterations", error.iterations())
+        );
+    }
+
+    #[test]
+    fn cancel_from_another_thread() {
+        let budget = Budget::unlimited();
+        let canceller = budget.clone();
+        let error = with_budget(budget, || {
+            let progress = Progress::new("test", Some(10));
+            progress.advance(3).unwrap();
+            thread::spawn(move || canceller.cancel()).join().unwrap();
+            progress.tick().unwrap_err()
+        });
+        assert!(!error.timed_out());
+        assert_eq!(error.to_string(), "cancelled after 4 iterations");
+    }
+
+    #[test]
+    fn budget_is_restored_after_with_budget() {
+        with_budget(Budget::timeout(Duration::ZERO), || {
+            assert!(Progress::new("test", None).tick().is_err());
+        });
+        assert!(Progress::new("test", None).tick().is_ok());
+    }
+
+    #[test]
+    fn budget_is_restored_after_a_panic() {
+        let result =
+            catch_panic(|| with_budget(Budget::timeout(Duration::ZERO), || panic!("solver bug")));
+        assert!(result.is_err());
+        assert!(Progress::new("test", None).tick().is_ok());
+    }
+
+    #[test]
+    fn reports_rate_and_eta() {
+        init_logging("info".parse().unwrap());
+        let budget = Budget::unlimited().report_every(Duration::ZERO);
+        let ((), diagnostics) = capture(|| {
+            with_budget(budget, || {
+                let progress = Progress::new("aoc2023_14_2", Some(200));
+                progress.advance(50).unwrap();
+            })
+        });
+        assert_eq!(diagnostics.len(), 1);
+        let message = &diagnostics[0].message;
+        assert!(message.starts_with("50 iterations, "), "{}", message);
+        assert!(message.contains("/s, 25.0% of 200, ETA "), "{}", message);
+    }
+}
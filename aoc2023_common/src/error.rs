@@ -0,0 +1,327 @@
+use std::error;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use anyhow::Error;
+
+/// Longest stretch of a line shown under an error; longer lines are cut down around the column.
+const SNIPPET_WIDTH: usize = 60;
+
+/// A problem with the puzzle input, saying which day it was for and, when known, where in the
+/// input it is.
+///
+/// Solvers return it through `anyhow::Error` like any other error; [`run`](crate::run) and
+/// [`solve`](crate::solve) fill in the day. Its `Display` points at the offending spot:
+///
+/// ```text
+/// day 7, line 2, column 3: invalid card 'X'
+///     2 | 32X3K 765
+///       |   ^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InputError {
+    day: Option<u8>,
+    location: Option<Location>,
+    message: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Location {
+    line: usize,
+    /// 1-based, counted in characters.
+    column: Option<usize>,
+    snippet: String,
+    /// Characters before the column within `snippet`.
+    caret: usize,
+}
+
+impl InputError {
+    /// An error about the input as a whole rather than one spot in it.
+    pub fn new<M: Into<String>>(message: M) -> Self {
+        Self {
+            day: None,
+            location: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    /// The 1-based line the error is on, if it is tied to one.
+    pub fn line(&self) -> Option<usize> {
+        self.location.as_ref().map(|location| location.line)
+    }
+
+    /// The 1-based column, in characters, the error points at.
+    pub fn column(&self) -> Option<usize> {
+        self.location.as_ref().and_then(|location| location.column)
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Fills in the day if it isn't known yet.
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut prefix = Vec::new();
+        if let Some(day) = self.day {
+            prefix.push(format!("day {}", day));
+        }
+        if let Some(location) = &self.location {
+            prefix.push(format!("line {}", location.line));
+            if let Some(column) = location.column {
+                prefix.push(format!("column {}", column));
+            }
+        }
+        if !prefix.is_empty() {
+            write!(f, "{}: ", prefix.join(", "))?;
+        }
+        write!(f, "{}", self.message)?;
+
+        if let Some(location) = &self.location {
+            let gutter = location.line.to_string().len() + 4;
+            write!(
+                f,
+                "\n{:>gutter$} | {}",
+                location.line,
+                location.snippet,
+                gutter = gutter
+            )?;
+            if location.column.is_some() {
+                write!(
+                    f,
+                    "\n{:>gutter$} | {:caret$}^",
+                    "",
+                    "",
+                    gutter = gutter,
+                    caret = location.caret
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for InputError {}
+
+/// Attaches `day` to `error` if it is (or wraps) an [`InputError`] that doesn't have one yet.
+pub(crate) fn attach_day(mut error: Error, day: u8) -> Error {
+    if let Some(input_error) = error.downcast_mut::<InputError>() {
+        input_error.day.get_or_insert(day);
+    }
+    error
+}
+
+/// One line of puzzle input together with its 1-based line number, so errors about it can say
+/// where they are. Dereferences to the line's text.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Line<'a> {
+    number: usize,
+    text: &'a str,
+}
+
+/// The lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self { number, text }
+    }
+
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// An error pointing at byte `offset` of this line.
+    pub fn error<M: Into<String>>(&self, offset: usize, message: M) -> InputError {
+        let offset = offset.min(self.text.len());
+        let column = self
+            .text
+            .get(..offset)
+            .map_or(offset, |before| before.chars().count());
+        self.error_at_column(Some(column), message.into())
+    }
+
+    /// An error pointing at `fragment`, which should be part of this line (as a `&str` or
+    /// `&[u8]` slice). Points at the whole line if it isn't.
+    pub fn error_at<F, M>(&self, fragment: &F, message: M) -> InputError
+    where
+        F: AsRef<[u8]> + ?Sized,
+        M: Into<String>,
+    {
+        match self.offset_of(fragment.as_ref()) {
+            Some(offset) => self.error(offset, message),
+            None => self.error_at_column(None, message.into()),
+        }
+    }
+
+    /// An error about the line as a whole.
+    pub fn error_here<M: Into<String>>(&self, message: M) -> InputError {
+        self.error_at_column(None, message.into())
+    }
+
+    /// Parses `fragment` of this line, reporting where it is if it isn't a valid `T`.
+    pub fn parse<T>(&self, fragment: &str) -> Result<T, InputError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        fragment
+            .parse()
+            .map_err(|e| self.error_at(fragment, format!("invalid value {:?}: {}", fragment, e)))
+    }
+
+    /// Turns any error into an [`InputError`] on this line, keeping the position of an
+    /// `InputError` that already has one.
+    pub fn wrap(&self, error: Error) -> Error {
+        match error.downcast::<InputError>() {
+            Ok(input_error) if input_error.location.is_some() => input_error.into(),
+            Ok(input_error) => self.error_here(input_error.message).into(),
+            Err(error) => self.error_here(format!("{:#}", error)).into(),
+        }
+    }
+
+    fn offset_of(&self, fragment: &[u8]) -> Option<usize> {
+        let start = self.text.as_ptr() as usize;
+        let offset = (fragment.as_ptr() as usize).checked_sub(start)?;
+        (offset + fragment.len() <= self.text.len()).then_some(offset)
+    }
+
+    fn error_at_column(&self, column: Option<usize>, message: String) -> InputError {
+        let chars = self.text.chars().collect::<Vec<_>>();
+        let (start, end) = match column {
+            Some(column) if chars.len() > SNIPPET_WIDTH => {
+                let start = column
+                    .saturating_sub(SNIPPET_WIDTH / 2)
+                    .min(chars.len() - SNIPPET_WIDTH);
+                (start, start + SNIPPET_WIDTH)
+            }
+            _ => (0, chars.len().min(SNIPPET_WIDTH)),
+        };
+        let mut snippet = chars[start..end].iter().collect::<String>();
+        let mut caret = column.map_or(0, |column| column - start);
+        if start > 0 {
+            snippet.insert_str(0, "...");
+            caret += 3;
+        }
+        if end < chars.len() {
+            snippet.push_str("...");
+        }
+
+        InputError {
+            day: None,
+            location: Some(Location {
+                line: self.number,
+                column: column.map(|column| column + 1),
+                snippet,
+                caret,
+            }),
+            message,
+        }
+    }
+}
+
+impl Deref for Line<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_points_at_fragment() {
+        let line = lines("32T3K 765\n32X3K 765\n").nth(1).unwrap();
+        let error = line.error_at(&line[2..3], "invalid card 'X'").with_day(7);
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.column(), Some(3));
+        assert_eq!(
+            error.to_string(),
+            "day 7, line 2, column 3: invalid card 'X'\n    2 | 32X3K 765\n      |   ^"
+        );
+    }
+
+    #[test]
+    fn error_without_location() {
+        let error = InputError::new("no loop found");
+        assert_eq!(error.to_string(), "no loop found");
+        assert_eq!(error.with_day(10).to_string(), "day 10: no loop found");
+    }
+
+    #[test]
+    fn error_on_whole_line() {
+        let line = Line::new(12, "abc");
+        let error = line.error_here("missing space");
+        assert_eq!(error.column(), None);
+        assert_eq!(error.to_string(), "line 12: missing space\n    12 | abc");
+    }
+
+    #[test]
+    fn long_lines_are_cut_around_the_column() {
+        let text = "a".repeat(100) + "X" + &"b".repeat(100);
+        let line = Line::new(1, &text);
+        let error = line.error(100, "bad");
+        let rendered = error.to_string();
+        let mut rows = rendered.lines().skip(1);
+        let snippet = rows.next().unwrap();
+        let caret = rows.next().unwrap();
+        assert!(snippet.ends_with("..."));
+        assert_eq!(snippet.find('X'), caret.find('^'));
+        assert_eq!(error.column(), Some(101));
+    }
+
+    #[test]
+    fn parse_reports_fragment() {
+        let line = Line::new(3, "Time: 7 1x 30");
+        let fragment = line.split(' ').nth(2).unwrap();
+        let error = line.parse::<u32>(fragment).unwrap_err();
+        assert_eq!(error.column(), Some(9));
+        assert!(error.message().starts_with("invalid value \"1x\""));
+        assert_eq!(line.parse::<u32>("x").unwrap_err().column(), None);
+    }
+
+    #[test]
+    fn wrap_keeps_existing_location() {
+        let line = Line::new(4, "abc");
+        let located = Error::from(Line::new(2, "xyz").error(1, "bad"));
+        let wrapped = line.wrap(located).downcast::<InputError>().unwrap();
+        assert_eq!((wrapped.line(), wrapped.column()), (Some(2), Some(2)));
+
+        let wrapped = line
+            .wrap(Error::msg("oops"))
+            .downcast::<InputError>()
+            .unwrap();
+        assert_eq!((wrapped.line(), wrapped.column()), (Some(4), None));
+        assert_eq!(wrapped.message(), "oops");
+    }
+
+    #[test]
+    fn attach_day_reaches_through_context() {
+        let error = Error::from(InputError::new("bad")).context("while parsing");
+        let error = attach_day(error, 5);
+        assert_eq!(error.downcast_ref::<InputError>().unwrap().day(), Some(5));
+    }
+}
@@ -0,0 +1,271 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use anyhow::Error;
+
+/// A compass direction on a grid where `y` grows downwards.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Parses `U`/`D`/`L`/`R`, `N`/`E`/`S`/`W` or an arrow (`^`, `>`, `v`, `<` or `↑`, `→`, `↓`,
+    /// `←`).
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | 'N' | '^' | '↑' => Some(Direction::Up),
+            'R' | 'E' | '>' | '→' => Some(Direction::Right),
+            'D' | 'S' | 'v' | 'V' | '↓' => Some(Direction::Down),
+            'L' | 'W' | '<' | '←' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    /// The unit step taken when moving this way.
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+}
+
+/// Parses the `U`, `D`, `L` or `R` that puzzle inputs use. [`Direction::from_char`] is the one
+/// that also takes compass letters and arrows.
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "U" => Ok(Direction::Up),
+            "R" => Ok(Direction::Right),
+            "D" => Ok(Direction::Down),
+            "L" => Ok(Direction::Left),
+            _ => Err(Error::msg(format!("invalid direction {:?}", s))),
+        }
+    }
+}
+
+/// A signed position or displacement, with `y` growing downwards.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The smallest point that is at least as far up and left as both.
+    pub fn min(self, other: Self) -> Self {
+        Self::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// The largest point that is at least as far down and right as both.
+    pub fn max(self, other: Self) -> Self {
+        Self::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    /// This point as an unsigned `(x, y)` position, if neither coordinate is negative.
+    pub fn to_position(self) -> Option<(usize, usize)> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x as i64, y as i64)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.turn_left().turn_left(), direction.reverse());
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_ne!(direction.is_horizontal(), direction.is_vertical());
+            assert_eq!(
+                direction.turn_right().is_horizontal(),
+                direction.is_vertical()
+            );
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Right.turn_right(), Direction::Down);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+    }
+
+    #[test]
+    fn offsets_are_unit_steps() {
+        assert_eq!(Direction::Up.offset(), Point::new(0, -1));
+        assert_eq!(Direction::Right.offset(), Point::new(1, 0));
+        assert_eq!(Direction::Down.offset(), Point::new(0, 1));
+        assert_eq!(Direction::Left.offset(), Point::new(-1, 0));
+        for direction in Direction::ALL {
+            assert_eq!(direction.reverse().offset(), -direction.offset());
+            assert_eq!(
+                Point::default().step(direction).manhattan(Point::default()),
+                1
+            );
+        }
+    }
+
+    #[test]
+    fn parse_directions() {
+        for (text, direction) in [
+            ("U", Direction::Up),
+            ("R", Direction::Right),
+            ("D", Direction::Down),
+            ("L", Direction::Left),
+        ] {
+            assert_eq!(text.parse::<Direction>().unwrap(), direction);
+        }
+        assert_eq!(Direction::from_char('E'), Some(Direction::Right));
+        assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+        assert_eq!(Direction::from_char('←'), Some(Direction::Left));
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(Direction::from_char('u'), None);
+        for text in ["", "X", "N", "^", "UD", "up"] {
+            let error = text.parse::<Direction>().unwrap_err();
+            assert_eq!(error.to_string(), format!("invalid direction {:?}", text));
+        }
+    }
+
+    #[test]
+    fn point_arithmetic() {
+        let a = Point::new(2, -3);
+        let b = Point::new(-5, 7);
+        assert_eq!(a + b, Point::new(-3, 4));
+        assert_eq!(a - b, Point::new(7, -10));
+        assert_eq!(a * 3, Point::new(6, -9));
+        assert_eq!(a * -1, -a);
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, a + b);
+        c -= b;
+        assert_eq!(c, a);
+
+        assert_eq!(a.manhattan(b), 17);
+        assert_eq!(a.min(b), Point::new(-5, -3));
+        assert_eq!(a.max(b), Point::new(2, 7));
+        assert_eq!(a.step(Direction::Up), Point::new(2, -4));
+    }
+
+    #[test]
+    fn positions() {
+        assert_eq!(Point::new(3, 4).to_position(), Some((3, 4)));
+        assert_eq!(Point::new(-1, 4).to_position(), None);
+        assert_eq!(Point::new(3, -4).to_position(), None);
+        assert_eq!(Point::from((3, 4)), Point::new(3, 4));
+    }
+}
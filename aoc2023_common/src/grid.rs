@@ -0,0 +1,420 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use anyhow::Error;
+
+use crate::error::{lines, InputError, Line};
+use crate::geometry::{Direction, Point};
+
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of cells stored row by row, addressed by `(x, y)` with `(0, 0)` at the top
+/// left.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from `cells` laid out row by row, `width` cells per row.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Result<Self, Error> {
+        if width == 0 {
+            if !cells.is_empty() {
+                return Err(Error::msg("grid with cells must have a non-zero width"));
+            }
+            return Ok(Self {
+                cells,
+                width,
+                height: 0,
+            });
+        }
+        if !cells.len().is_multiple_of(width) {
+            return Err(Error::msg(format!(
+                "{} cells don't fill rows of width {}",
+                cells.len(),
+                width
+            )));
+        }
+        let height = cells.len() / width;
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Parses one row per line of `input`, converting each byte with `cell`. Every line must be
+    /// as wide as the first. Errors from `cell` are reported at the cell's line and column.
+    pub fn parse_with<F>(input: &str, cell: F) -> Result<Self, Error>
+    where
+        F: FnMut(u8) -> Result<T, Error>,
+    {
+        Self::parse_lines_with(lines(input), cell)
+    }
+
+    /// Like [`Grid::parse_with`], for lines taken from a larger input so errors keep their line
+    /// numbers.
+    pub fn parse_lines_with<'a, I, F>(lines: I, mut cell: F) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = Line<'a>>,
+        F: FnMut(u8) -> Result<T, Error>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+
+        for line in lines {
+            let width = *width.get_or_insert(line.len());
+            if line.len() != width {
+                return Err(line
+                    .error(
+                        width.min(line.len()),
+                        format!("line is {} wide, expected {}", line.len(), width),
+                    )
+                    .into());
+            }
+            for (x, b) in line.bytes().enumerate() {
+                cells.push(cell(b).map_err(|e| match e.downcast::<InputError>() {
+                    Ok(e) => e.into(),
+                    Err(e) => Error::from(line.error(x, format!("{:#}", e))),
+                })?);
+            }
+        }
+
+        Self::from_cells(width.unwrap_or(0), cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// All cells, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Every `(x, y)` in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// The first position, row by row, whose cell matches `predicate`.
+    pub fn position<P>(&self, predicate: P) -> Option<(usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let i = self.cells.iter().position(predicate)?;
+        Some((i % self.width, i / self.width))
+    }
+
+    /// `(x, y)` moved by `(dx, dy)`, or `None` if that leaves the grid.
+    pub fn offset(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx).filter(|x| *x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|y| *y < self.height)?;
+        Some((x, y))
+    }
+
+    /// `pos` moved one cell towards `direction`, or `None` if that leaves the grid.
+    pub fn step(&self, pos: (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        self.position_of(Point::from(pos).step(direction))
+    }
+
+    /// The unsigned position of `point`, or `None` if it lies outside the grid.
+    pub fn position_of(&self, point: Point) -> Option<(usize, usize)> {
+        point
+            .to_position()
+            .filter(|(x, y)| *x < self.width && *y < self.height)
+    }
+
+    /// The orthogonal neighbours of `(x, y)` that lie inside the grid, clockwise from up.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step((x, y), direction))
+    }
+
+    /// The orthogonal and diagonal neighbours of `(x, y)` that lie inside the grid, row by row.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS8
+            .iter()
+            .filter_map(move |(dx, dy)| self.offset(x, y, *dx, *dy))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f(x, y));
+            }
+        }
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Rotates the grid a quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise, so the right column becomes the top
+    /// row.
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl Grid<u8> {
+    /// Parses one row of raw bytes per line of `input`.
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        Self::parse_with(input, Ok)
+    }
+
+    /// Like [`Grid::parse`], for lines taken from a larger input.
+    pub fn parse_lines<'a, I>(lines: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = Line<'a>>,
+    {
+        Self::parse_lines_with(lines, Ok)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) out of bounds", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) out of bounds", x, y))
+    }
+}
+
+impl<T: Copy + Into<char>> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", (*cell).into())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 3 wide, 2 tall grid:
+    ///
+    /// ```text
+    /// abc
+    /// def
+    /// ```
+    fn letters() -> Grid<u8> {
+        Grid::parse("abc\ndef\n").unwrap()
+    }
+
+    #[test]
+    fn parse_and_display_round_trip() {
+        let grid = letters();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], b'f');
+        assert_eq!(grid.row(1), b"def");
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), b"be");
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        let empty = Grid::parse("").unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.to_string(), "");
+    }
+
+    #[test]
+    fn ragged_rows_are_rejected() {
+        let error = Grid::parse("abc\nab\nabc\n").unwrap_err();
+        let error = error.downcast::<InputError>().unwrap();
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.column(), Some(3));
+        assert_eq!(error.message(), "line is 2 wide, expected 3");
+
+        let error = Grid::parse("ab\nabc\n").unwrap_err();
+        let error = error.downcast::<InputError>().unwrap();
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.message(), "line is 3 wide, expected 2");
+    }
+
+    #[test]
+    fn parse_with_locates_bad_cells() {
+        let error = Grid::parse_with("12\n3x\n", |b| {
+            (b as char)
+                .to_digit(10)
+                .ok_or_else(|| Error::msg("not a digit"))
+        })
+        .unwrap_err();
+        let error = error.downcast::<InputError>().unwrap();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(2)));
+        assert_eq!(error.message(), "not a digit");
+    }
+
+    #[test]
+    fn from_cells_needs_whole_rows() {
+        assert_eq!(Grid::from_cells(2, vec![1, 2, 3, 4]).unwrap().height(), 2);
+        assert!(Grid::from_cells(3, vec![1, 2, 3, 4]).is_err());
+        assert!(Grid::from_cells(0, vec![1]).is_err());
+        assert_eq!(Grid::<u8>::from_cells(0, vec![]).unwrap().height(), 0);
+    }
+
+    #[test]
+    fn transpose_swaps_rows_and_columns() {
+        let grid = letters().transpose();
+        assert_eq!(grid.to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.transpose(), letters());
+    }
+
+    #[test]
+    fn rotations() {
+        let grid = letters();
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+
+        let mut turned = grid.clone();
+        for _ in 0..4 {
+            turned = turned.rotate_clockwise();
+        }
+        assert_eq!(turned, grid);
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = Grid::new(3, 3, 0u8);
+
+        let corner = grid.neighbours4(0, 0).collect::<Vec<_>>();
+        assert_eq!(corner, [(1, 0), (0, 1)]);
+        let edge = grid.neighbours4(2, 1).collect::<Vec<_>>();
+        assert_eq!(edge, [(2, 0), (2, 2), (1, 1)]);
+        assert_eq!(grid.neighbours4(1, 1).count(), 4);
+
+        let corner = grid.neighbours8(2, 2).collect::<Vec<_>>();
+        assert_eq!(corner, [(1, 1), (2, 1), (1, 2)]);
+        let edge = grid.neighbours8(1, 0).collect::<Vec<_>>();
+        assert_eq!(edge, [(0, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    }
+
+    #[test]
+    fn moves_off_the_grid_are_none() {
+        let grid = letters();
+        assert_eq!(grid.step((0, 0), Direction::Up), None);
+        assert_eq!(grid.step((0, 0), Direction::Left), None);
+        assert_eq!(grid.step((2, 1), Direction::Right), None);
+        assert_eq!(grid.step((2, 1), Direction::Down), None);
+        assert_eq!(grid.step((1, 0), Direction::Down), Some((1, 1)));
+
+        assert_eq!(grid.offset(0, 0, -1, 0), None);
+        assert_eq!(grid.offset(1, 1, 2, 0), None);
+        assert_eq!(grid.offset(1, 1, 0, 1), None);
+        assert_eq!(grid.offset(0, 1, 2, -1), Some((2, 0)));
+
+        assert_eq!(grid.position_of(Point::new(-1, 0)), None);
+        assert_eq!(grid.position_of(Point::new(3, 0)), None);
+        assert_eq!(grid.get(0, 2), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "(3, 0) out of bounds")]
+    fn indexing_off_the_grid_panics() {
+        let _ = letters()[(3, 0)];
+    }
+}
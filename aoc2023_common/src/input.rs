@@ -0,0 +1,146 @@
+use std::borrow::Cow;
+
+use log::debug;
+
+use crate::solution::Part;
+
+/// Undoes the ways a saved input can differ from the one the site serves without meaning
+/// anything different: a byte order mark, CRLF or CR line endings, whitespace at the end of
+/// lines, blank lines at the end and a missing final newline. Whitespace never ends a line in
+/// any of the puzzle formats, so trimming it is always safe.
+///
+/// Returns the cleaned-up input, borrowed if nothing needed changing, and a description of
+/// each change made.
+pub fn normalize(input: &str) -> (Cow<'_, str>, Vec<String>) {
+    let mut changes = Vec::new();
+
+    let text = match input.strip_prefix('\u{feff}') {
+        Some(rest) => {
+            changes.push("removed a byte order mark".to_owned());
+            rest
+        }
+        None => input,
+    };
+
+    let crlf = text.matches("\r\n").count();
+    let cr = text.matches('\r').count() - crlf;
+    let unified;
+    let text = if crlf + cr == 0 {
+        text
+    } else {
+        for (count, ending) in [(crlf, "CRLF"), (cr, "CR")] {
+            if count != 0 {
+                changes.push(format!("converted {} {} line ending(s)", count, ending));
+            }
+        }
+        unified = text.replace("\r\n", "\n").replace('\r', "\n");
+        &unified
+    };
+
+    let body = match text.strip_suffix('\n') {
+        Some(body) => body,
+        None => {
+            if !text.trim().is_empty() {
+                changes.push("added the missing final newline".to_owned());
+            }
+            text
+        }
+    };
+    let mut lines = body.split('\n').collect::<Vec<_>>();
+    let mut trimmed = 0;
+    for line in lines.iter_mut() {
+        let end = line.trim_end();
+        if end.len() != line.len() {
+            *line = end;
+            trimmed += 1;
+        }
+    }
+    if trimmed != 0 {
+        changes.push(format!(
+            "trimmed trailing whitespace from {} line(s)",
+            trimmed
+        ));
+    }
+    let content = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |i| i + 1);
+    let blank = if text.is_empty() {
+        0
+    } else {
+        lines.len() - content
+    };
+    if blank != 0 {
+        changes.push(format!("removed {} blank line(s) at the end", blank));
+    }
+
+    if changes.is_empty() {
+        return (Cow::Borrowed(input), changes);
+    }
+    let mut normalized = String::with_capacity(text.len());
+    for line in &lines[..content] {
+        normalized.push_str(line);
+        normalized.push('\n');
+    }
+    (Cow::Owned(normalized), changes)
+}
+
+/// [`normalize`]s the input to `part` of `day`, logging the changes at debug level under the
+/// day's crate.
+pub(crate) fn normalize_for(day: u8, part: Part, input: &str) -> Cow<'_, str> {
+    let (input, changes) = normalize(input);
+    if !changes.is_empty() {
+        let target = match part {
+            Part::One => format!("aoc2023_{:02}::input", day),
+            Part::Two => format!("aoc2023_{:02}_2::input", day),
+        };
+        for change in changes {
+            debug!(target: &target, "{}", change);
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clean_input_is_borrowed() {
+        for input in ["", "a\n", "a\n\nb\n"] {
+            let (normalized, changes) = normalize(input);
+            assert!(matches!(normalized, Cow::Borrowed(_)), "{:?}", input);
+            assert!(changes.is_empty(), "{:?}", changes);
+        }
+    }
+
+    #[test]
+    fn normalizes_saved_inputs() {
+        let (normalized, changes) = normalize("\u{feff}#.# \r\n\t\r\n..#\r\r\n\n");
+        assert_eq!(normalized, "#.#\n\n..#\n");
+        assert_eq!(
+            changes,
+            [
+                "removed a byte order mark",
+                "converted 3 CRLF line ending(s)",
+                "converted 1 CR line ending(s)",
+                "trimmed trailing whitespace from 2 line(s)",
+                "removed 2 blank line(s) at the end",
+            ]
+        );
+
+        let (normalized, changes) = normalize("rn=1,cm-");
+        assert_eq!(normalized, "rn=1,cm-\n");
+        assert_eq!(changes, ["added the missing final newline"]);
+
+        let (normalized, changes) = normalize(" \n\n");
+        assert_eq!(normalized, "");
+        assert_eq!(
+            changes,
+            [
+                "trimmed trailing whitespace from 1 line(s)",
+                "removed 2 blank line(s) at the end"
+            ]
+        );
+    }
+}
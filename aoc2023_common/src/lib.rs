@@ -0,0 +1,29 @@
+mod answer;
+mod budget;
+mod error;
+mod geometry;
+mod grid;
+mod input;
+mod logging;
+mod panic;
+mod params;
+mod report;
+mod solution;
+mod strategy;
+
+pub use answer::{Answer, CheckedIterator, Overflow};
+pub use budget::{with_budget, Budget, Interrupted, Progress};
+pub use error::{lines, InputError, Line};
+pub use geometry::{Direction, Point};
+pub use grid::Grid;
+pub use input::normalize;
+pub use logging::{
+    capture, init_logging, init_logging_from, CaptureSink, Diagnostic, Entered, LogFilter, LOG_ENV,
+};
+pub use panic::{catch_panic, panic_message};
+pub use params::{param, with_params, Param, Params};
+pub use report::{main, parse_timeout, Report};
+pub use solution::{
+    run, solve, solve_reader, spawn_solver, Part, Run, Solution, SOLVER_STACK_SIZE,
+};
+pub use strategy::{cross_check, Strategy, StrategyRun};
@@ -0,0 +1,307 @@
+use std::cell::RefCell;
+use std::env;
+use std::mem;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, RwLock};
+
+use anyhow::{Context, Error};
+use log::{LevelFilter, Log, Metadata, Record};
+use serde::Serialize;
+
+/// Environment variable read for the log filter when no `--log` flag is given.
+pub const LOG_ENV: &str = "AOC_LOG";
+
+static FILTER: RwLock<LogFilter> = RwLock::new(LogFilter::DEFAULT);
+static LOGGER: Logger = Logger;
+
+type Captured = Arc<Mutex<Vec<Diagnostic>>>;
+
+thread_local! {
+    static CAPTURED: RefCell<Option<Captured>> = const { RefCell::new(None) };
+}
+
+/// Which log levels to show, overall and per target.
+///
+/// The syntax is a comma-separated list of `level` or `target=level` directives, e.g.
+/// `info,aoc2023_10_2=trace`. A target also matches its submodules, and a bare day such as `10`
+/// or `5` stands for both of that day's crates while `10_2` stands for `aoc2023_10_2` alone. The
+/// most specific directive wins.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LogFilter {
+    default: LevelFilter,
+    targets: Vec<(String, LevelFilter)>,
+}
+
+impl LogFilter {
+    pub const DEFAULT: LogFilter = LogFilter {
+        default: LevelFilter::Info,
+        targets: Vec::new(),
+    };
+
+    /// The most verbose level enabled for `target`.
+    pub fn level(&self, target: &str) -> LevelFilter {
+        self.targets
+            .iter()
+            .filter(|(prefix, _)| {
+                target
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.targets
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+impl FromStr for LogFilter {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let mut filter = LogFilter::DEFAULT;
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            let Some((target, level)) = directive.split_once('=') else {
+                filter.default = parse_level(directive)?;
+                continue;
+            };
+            let level = parse_level(level)?;
+            if target.starts_with(|c: char| c.is_ascii_digit()) {
+                let (day, part2_only) = match target.strip_suffix("_2") {
+                    Some(day) => (day, true),
+                    None => (target, false),
+                };
+                let day = day
+                    .parse::<u8>()
+                    .ok()
+                    .with_context(|| format!("invalid day {:?}", target))?;
+                let crate_name = format!("aoc2023_{:02}", day);
+                filter.targets.push((format!("{}_2", crate_name), level));
+                if !part2_only {
+                    filter.targets.push((crate_name, level));
+                }
+            } else {
+                filter.targets.push((target.to_owned(), level));
+            }
+        }
+        Ok(filter)
+    }
+}
+
+fn parse_level(text: &str) -> Result<LevelFilter, Error> {
+    text.parse()
+        .ok()
+        .with_context(|| format!("invalid log level {:?}", text))
+}
+
+/// One log record emitted while [`capture`] was active.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Diagnostic {
+    pub level: String,
+    pub target: String,
+    pub message: String,
+}
+
+struct Logger;
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        let filter = FILTER.read().unwrap_or_else(|e| e.into_inner());
+        metadata.level() <= filter.level(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let text = record.args().to_string();
+        // whole grids are logged as one record, so drop the newline their Display ends with
+        let message = text.strip_suffix('\n').unwrap_or(&text);
+        CAPTURED.with_borrow(|captured| match captured {
+            Some(diagnostics) => {
+                diagnostics
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .push(Diagnostic {
+                        level: record.level().as_str().to_ascii_lowercase(),
+                        target: record.target().to_owned(),
+                        message: message.to_owned(),
+                    })
+            }
+            // put multi-line messages below the header so grids stay aligned
+            None if message.contains('\n') => {
+                eprintln!("[{} {}]\n{}", record.level(), record.target(), message)
+            }
+            None => eprintln!("[{} {}] {}", record.level(), record.target(), message),
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs the logger with `filter`, replacing the filter if it is already installed.
+pub fn init_logging(filter: LogFilter) {
+    log::set_max_level(filter.max_level());
+    *FILTER.write().unwrap_or_else(|e| e.into_inner()) = filter;
+    // only fails if the logger is already installed, which is fine
+    let _ = log::set_logger(&LOGGER);
+}
+
+/// Installs the logger with the filter given by a `--log` flag, falling back to [`LOG_ENV`] and
+/// then to `info`.
+pub fn init_logging_from(flag: Option<&str>) -> Result<(), Error> {
+    let filter = match flag {
+        Some(spec) => spec.parse().context("--log")?,
+        None => match env::var(LOG_ENV) {
+            Ok(spec) => spec.parse().context(LOG_ENV)?,
+            Err(_) => LogFilter::DEFAULT,
+        },
+    };
+    init_logging(filter);
+    Ok(())
+}
+
+/// Runs `f`, collecting the log records it emits instead of printing them.
+///
+/// Only records from this thread are collected. Threads `f` hands work to, such as rayon's, print
+/// theirs unless they [`enter`](CaptureSink::enter) the [`CaptureSink`] taken from this one.
+pub fn capture<T, F>(f: F) -> (T, Vec<Diagnostic>)
+where
+    F: FnOnce() -> T,
+{
+    let diagnostics = Captured::default();
+    let value = {
+        let _entered = CaptureSink(Some(Arc::clone(&diagnostics))).enter();
+        f()
+    };
+    let diagnostics = mem::take(&mut *diagnostics.lock().unwrap_or_else(|e| e.into_inner()));
+    (value, diagnostics)
+}
+
+/// Where the [`capture`] running on a thread collects records, for passing to the threads it
+/// hands work to.
+#[derive(Clone, Debug, Default)]
+pub struct CaptureSink(Option<Captured>);
+
+impl CaptureSink {
+    /// The sink of the capture running on this thread, or one that prints if there is none.
+    pub fn current() -> Self {
+        Self(CAPTURED.with_borrow(Clone::clone))
+    }
+
+    /// Sends the records this thread emits to the sink until the guard is dropped.
+    pub fn enter(&self) -> Entered {
+        Entered(CAPTURED.replace(self.0.clone()))
+    }
+}
+
+/// Puts back what a thread's records went to before [`CaptureSink::enter`], even if the thread
+/// is unwinding from a panic.
+#[must_use = "records only go to the sink until this is dropped"]
+pub struct Entered(Option<Captured>);
+
+impl Drop for Entered {
+    fn drop(&mut self) {
+        CAPTURED.set(self.0.take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use log::{debug, info, trace};
+
+    use super::*;
+    use crate::panic::catch_panic;
+
+    #[test]
+    fn filter_picks_most_specific_target() {
+        let filter = "warn,aoc2023_10=debug,aoc2023_10::fill=trace"
+            .parse::<LogFilter>()
+            .unwrap();
+        assert_eq!(filter.level("aoc2023_01"), LevelFilter::Warn);
+        assert_eq!(filter.level("aoc2023_10"), LevelFilter::Debug);
+        assert_eq!(filter.level("aoc2023_10::fill"), LevelFilter::Trace);
+        assert_eq!(filter.level("aoc2023_10_2"), LevelFilter::Warn);
+        assert_eq!(filter.max_level(), LevelFilter::Trace);
+    }
+
+    #[test]
+    fn filter_expands_day_shorthand() {
+        let filter = "error,12=debug,10_2=trace".parse::<LogFilter>().unwrap();
+        assert_eq!(filter.level("aoc2023_12"), LevelFilter::Debug);
+        assert_eq!(filter.level("aoc2023_12_2"), LevelFilter::Debug);
+        assert_eq!(filter.level("aoc2023_10"), LevelFilter::Error);
+        assert_eq!(filter.level("aoc2023_10_2"), LevelFilter::Trace);
+    }
+
+    #[test]
+    fn filter_pads_single_digit_days() {
+        let filter = "error,1=trace,5_2=debug".parse::<LogFilter>().unwrap();
+        assert_eq!(filter.level("aoc2023_01"), LevelFilter::Trace);
+        assert_eq!(filter.level("aoc2023_01_2"), LevelFilter::Trace);
+        assert_eq!(filter.level("aoc2023_05"), LevelFilter::Error);
+        assert_eq!(filter.level("aoc2023_05_2"), LevelFilter::Debug);
+        assert!("1x=debug".parse::<LogFilter>().is_err());
+    }
+
+    #[test]
+    fn filter_rejects_unknown_level() {
+        assert!("loud".parse::<LogFilter>().is_err());
+        assert!("10=loud".parse::<LogFilter>().is_err());
+        assert_eq!("".parse::<LogFilter>().unwrap(), LogFilter::DEFAULT);
+    }
+
+    #[test]
+    fn capture_collects_nested_records_separately() {
+        init_logging("debug".parse().unwrap());
+        let (value, outer) = capture(|| {
+            info!("outer {}", 1);
+            let ((), inner) = capture(|| debug!("inner"));
+            assert_eq!(inner.len(), 1);
+            assert_eq!(inner[0].message, "inner");
+            trace!("filtered out");
+            debug!("outer {}\n", 2);
+            7
+        });
+        assert_eq!(value, 7);
+        let messages = outer.iter().map(|d| d.message.as_str()).collect::<Vec<_>>();
+        assert_eq!(messages, ["outer 1", "outer 2"]);
+        assert_eq!(outer[0].level, "info");
+        assert_eq!(outer[0].target, module_path!());
+    }
+
+    #[test]
+    fn capture_reaches_threads_that_enter_it() {
+        init_logging("debug".parse().unwrap());
+        let ((), diagnostics) = capture(|| {
+            let sink = CaptureSink::current();
+            thread::spawn(move || {
+                let _entered = sink.enter();
+                info!("from a worker");
+            })
+            .join()
+            .unwrap();
+        });
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "from a worker");
+    }
+
+    #[test]
+    fn capture_is_restored_after_a_panic() {
+        init_logging("debug".parse().unwrap());
+        let ((), outer) = capture(|| {
+            let result = catch_panic(|| capture(|| panic!("solver bug")));
+            assert!(result.is_err());
+            info!("after the panic");
+        });
+        let messages = outer.iter().map(|d| d.message.as_str()).collect::<Vec<_>>();
+        assert_eq!(messages, ["after the panic"]);
+        assert!(CAPTURED.with_borrow(Option::is_none));
+    }
+}
@@ -0,0 +1,76 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{self, catch_unwind, AssertUnwindSafe};
+use std::sync::Once;
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Runs `f`, returning what it said if it panicked.
+///
+/// The panic isn't printed, for callers that expect some of what they run to panic, such as a
+/// solver fed an input that isn't one. Panics on other threads still go to the hook that was
+/// installed before, which is left in place.
+pub fn catch_panic<T, F>(f: F) -> Result<T, String>
+where
+    F: FnOnce() -> T,
+{
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+
+    let quiet = QUIET.with(|cell| cell.replace(true));
+    let result = catch_unwind(AssertUnwindSafe(f));
+    QUIET.with(|cell| cell.set(quiet));
+    result.map_err(|payload| panic_message(&*payload).to_owned())
+}
+
+/// What a solver that panicked said, from the payload `catch_unwind` or `join` returned.
+pub fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown cause"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn panics_are_caught_quietly() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+        assert_eq!(
+            catch_panic(|| -> u8 { panic!("index out of bounds") }),
+            Err("index out of bounds".to_owned())
+        );
+        let nested = catch_panic(|| {
+            let inner = catch_panic(|| -> u8 { panic!("{} inner", 1) });
+            assert!(QUIET.with(Cell::get));
+            inner
+        });
+        assert_eq!(nested, Ok(Err("1 inner".to_owned())));
+        assert!(!QUIET.with(Cell::get));
+
+        // other threads are left to the previous hook
+        let panicked = thread::spawn(|| {
+            assert!(!QUIET.with(Cell::get));
+            panic!("elsewhere");
+        })
+        .join();
+        assert!(panicked.is_err());
+    }
+}
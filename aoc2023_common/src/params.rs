@@ -0,0 +1,191 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use anyhow::{Context, Error};
+
+use crate::solution::Solution;
+
+thread_local! {
+    static PARAMS: RefCell<Params> = RefCell::new(Params::default());
+}
+
+/// A puzzle constant a solver lets callers change, declared in [`Solution::PARAMS`].
+#[derive(Clone, Copy, Debug)]
+pub struct Param {
+    pub name: &'static str,
+    /// The value the puzzle itself uses.
+    pub default: &'static str,
+    pub help: &'static str,
+}
+
+/// Values for some of a solver's [`Param`]s, by name; the rest keep their defaults.
+///
+/// The runner installs them around a solver with [`with_params`], and the solver reads them
+/// through [`param`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn set<N: Into<String>, V: Into<String>>(&mut self, name: N, value: V) {
+        self.0.insert(name.into(), value.into());
+    }
+
+    /// Sets a parameter from `NAME=VALUE`.
+    pub fn set_assignment(&mut self, assignment: &str) -> Result<(), Error> {
+        let (name, value) = assignment
+            .split_once('=')
+            .with_context(|| format!("expected NAME=VALUE, not {:?}", assignment))?;
+        self.set(name, value);
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Runs `f` with `params` as the parameters of any solver it runs on this thread.
+pub fn with_params<T, F>(params: Params, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let _restore = RestoreParams(Some(PARAMS.replace(params)));
+    f()
+}
+
+/// Puts back the parameters [`with_params`] replaced, even if its closure panics.
+struct RestoreParams(Option<Params>);
+
+impl Drop for RestoreParams {
+    fn drop(&mut self) {
+        if let Some(outer) = self.0.take() {
+            PARAMS.set(outer);
+        }
+    }
+}
+
+/// The value of `S`'s parameter `name` on this thread, or its default if it wasn't set.
+///
+/// # Panics
+///
+/// If `S` doesn't declare `name` in [`Solution::PARAMS`].
+pub fn param<S, T>(name: &str) -> Result<T, Error>
+where
+    S: Solution,
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let declared = S::PARAMS
+        .iter()
+        .find(|param| param.name == name)
+        .unwrap_or_else(|| panic!("day {} declares no parameter {:?}", S::DAY, name));
+    let value = PARAMS.with_borrow(|params| params.get(name).map(str::to_owned));
+    let value = value.as_deref().unwrap_or(declared.default);
+    value
+        .parse()
+        .with_context(|| format!("invalid {} {:?}", name, value))
+}
+
+/// Fails if this thread's parameters include one `S` doesn't declare, which is most likely a
+/// typo.
+pub(crate) fn check_params<S: Solution>() -> Result<(), Error> {
+    PARAMS.with_borrow(|params| {
+        match params
+            .names()
+            .find(|name| S::PARAMS.iter().all(|param| param.name != *name))
+        {
+            Some(name) if S::PARAMS.is_empty() => Err(Error::msg(format!(
+                "day {} takes no parameters, but was given {:?}",
+                S::DAY,
+                name
+            ))),
+            Some(name) => Err(Error::msg(format!(
+                "day {} has no parameter {:?}, only {}",
+                S::DAY,
+                name,
+                S::PARAMS
+                    .iter()
+                    .map(|param| param.name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))),
+            None => Ok(()),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::panic::catch_panic;
+    use crate::solution::{solve, Part};
+
+    struct Scaled;
+
+    impl Solution for Scaled {
+        const DAY: u8 = 11;
+        const PARAMS: &'static [Param] = &[Param {
+            name: "factor",
+            default: "2",
+            help: "what to multiply by",
+        }];
+
+        type Parsed = u64;
+
+        fn parse(input: &str) -> Result<Self::Parsed, Error> {
+            Ok(input.trim().parse()?)
+        }
+
+        fn part1(value: &Self::Parsed) -> Result<Answer, Error> {
+            Ok((value * param::<Self, u64>("factor")?).into())
+        }
+    }
+
+    fn params(assignments: &[&str]) -> Params {
+        let mut params = Params::default();
+        for assignment in assignments {
+            params.set_assignment(assignment).unwrap();
+        }
+        params
+    }
+
+    #[test]
+    fn defaults_and_overrides() {
+        assert_eq!(solve::<Scaled>(Part::One, "21").unwrap(), "42");
+        let answer = with_params(params(&["factor=3"]), || solve::<Scaled>(Part::One, "21"));
+        assert_eq!(answer.unwrap(), "63");
+        assert_eq!(solve::<Scaled>(Part::One, "21").unwrap(), "42");
+    }
+
+    #[test]
+    fn bad_params_are_errors() {
+        let error = with_params(params(&["factr=3"]), || solve::<Scaled>(Part::One, "21"));
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "day 11 has no parameter \"factr\", only factor"
+        );
+        let error = with_params(params(&["factor=x"]), || solve::<Scaled>(Part::One, "21"));
+        assert_eq!(
+            format!("{:#}", error.unwrap_err()),
+            "invalid factor \"x\": invalid digit found in string"
+        );
+        assert!(Params::default().set_assignment("factor").is_err());
+    }
+
+    #[test]
+    fn params_are_restored_after_a_panic() {
+        let result = catch_panic(|| with_params(params(&["factor=3"]), || panic!("solver bug")));
+        assert!(result.is_err());
+        assert_eq!(solve::<Scaled>(Part::One, "21").unwrap(), "42");
+    }
+}
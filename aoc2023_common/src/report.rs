@@ -0,0 +1,191 @@
+use std::env::args;
+use std::io::{read_to_string, stdin};
+use std::time::{Duration, Instant};
+
+use anyhow::Error;
+use serde::Serialize;
+
+use crate::budget::{with_budget, Budget, Interrupted};
+use crate::logging::{capture, init_logging_from, Diagnostic};
+use crate::params::{with_params, Params};
+use crate::solution::{run, Part, Run, Solution};
+
+/// The outcome of one part in the shape `--json` prints it.
+#[derive(Clone, Debug, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    /// `None` if the part failed, in which case `error` says why.
+    pub answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Whether the part was stopped by its [`Budget`](crate::Budget) rather than failing.
+    #[serde(skip_serializing_if = "is_false")]
+    pub interrupted: bool,
+    /// Parse and solve time together, in seconds.
+    pub elapsed: f64,
+    /// Everything the solver logged while running that the log filter let through.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Report {
+    /// Runs `part` of `S` on `input`, capturing its diagnostics.
+    pub fn new<S: Solution>(part: Part, input: &str) -> Self {
+        Self::from_run(S::DAY, part, || run::<S>(part, input))
+    }
+
+    /// Builds a report from a runner such as [`run`], capturing its diagnostics. A failed run
+    /// reports how long it took to fail.
+    pub fn from_run<F>(day: u8, part: Part, f: F) -> Self
+    where
+        F: FnOnce() -> Result<Run, Error>,
+    {
+        let start = Instant::now();
+        let (result, diagnostics) = capture(f);
+        let failed_after = start.elapsed();
+        let (answer, error, interrupted, elapsed) = match result {
+            Ok(run) => (
+                Some(run.answer),
+                None,
+                false,
+                (run.parse_time + run.solve_time).as_secs_f64(),
+            ),
+            Err(e) => (
+                None,
+                Some(format!("{:#}", e)),
+                e.downcast_ref::<Interrupted>().is_some(),
+                failed_after.as_secs_f64(),
+            ),
+        };
+        Self {
+            day,
+            part: part.number(),
+            answer,
+            error,
+            interrupted,
+            elapsed,
+            diagnostics,
+        }
+    }
+
+    /// The report as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("reports always serialize")
+    }
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+/// Entry point shared by the day binaries: solves `part` of `S` from stdin and prints the bare
+/// answer, or a [`Report`] as JSON when given `--json`. `--log FILTER` sets the log filter (see
+/// [`LogFilter`](crate::LogFilter)), `--timeout SECS` gives up on long loops after that long and
+/// `--param NAME=VALUE` changes one of the part's [`PARAMS`](Solution::PARAMS).
+pub fn main<S: Solution>(part: Part) -> Result<(), Error> {
+    let args = args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+    let mut args = args.as_slice();
+
+    let mut json = false;
+    let mut log = None;
+    let mut budget = Budget::unlimited();
+    let mut params = Params::default();
+    loop {
+        match args {
+            [] => break,
+            ["--json", rest @ ..] => {
+                json = true;
+                args = rest;
+            }
+            ["--log", filter, rest @ ..] => {
+                log = Some(*filter);
+                args = rest;
+            }
+            ["--timeout", seconds, rest @ ..] => {
+                budget = Budget::timeout(parse_timeout(seconds)?);
+                args = rest;
+            }
+            ["--param", assignment, rest @ ..] => {
+                params.set_assignment(assignment)?;
+                args = rest;
+            }
+            _ => return Err(Error::msg(
+                "usage: [--json] [--log FILTER] [--timeout SECS] [--param NAME=VALUE]... < input",
+            )),
+        }
+    }
+    init_logging_from(log)?;
+
+    let input = read_to_string(stdin())?;
+    let solve = || with_budget(budget, || with_params(params, || run::<S>(part, &input)));
+    if !json {
+        println!("{}", solve()?.answer);
+        return Ok(());
+    }
+
+    let report = Report::from_run(S::DAY, part, solve);
+    println!("{}", report.to_json());
+    match report.error {
+        Some(error) => Err(Error::msg(error)),
+        None => Ok(()),
+    }
+}
+
+/// Parses a `--timeout` in seconds, which may be fractional.
+pub fn parse_timeout(seconds: &str) -> Result<Duration, Error> {
+    seconds
+        .parse::<f64>()
+        .ok()
+        .filter(|seconds| *seconds > 0.0 && seconds.is_finite())
+        .map(Duration::from_secs_f64)
+        .ok_or_else(|| Error::msg(format!("invalid timeout {:?}", seconds)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::budget::Progress;
+    use crate::logging::init_logging;
+
+    #[test]
+    fn report_includes_answer_and_diagnostics() {
+        init_logging("debug".parse().unwrap());
+        let report = Report::from_run(3, Part::Two, || {
+            log::info!(target: "aoc2023_03_2", "checking");
+            Ok(Run {
+                answer: "42".to_owned(),
+                parse_time: Duration::from_millis(250),
+                solve_time: Duration::from_millis(250),
+            })
+        });
+        assert_eq!(
+            report.to_json(),
+            r#"{"day":3,"part":2,"answer":"42","elapsed":0.5,"diagnostics":[{"level":"info","target":"aoc2023_03_2","message":"checking"}]}"#
+        );
+    }
+
+    #[test]
+    fn report_includes_error() {
+        let mut report = Report::from_run(3, Part::One, || Err(Error::msg("bad input")));
+        report.elapsed = 0.0;
+        assert_eq!(
+            report.to_json(),
+            r#"{"day":3,"part":1,"answer":null,"error":"bad input","elapsed":0.0,"diagnostics":[]}"#
+        );
+    }
+
+    #[test]
+    fn report_times_failures() {
+        let report = with_budget(Budget::timeout(Duration::from_millis(50)), || {
+            Report::from_run(3, Part::One, || {
+                let progress = Progress::new("test", None);
+                loop {
+                    progress.tick()?;
+                }
+            })
+        });
+        assert!(report.interrupted);
+        assert!(report.elapsed >= 0.05, "{}", report.elapsed);
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt;
+use std::io::{self, read_to_string, BufRead};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use anyhow::Error;
+
+use crate::answer::Answer;
+use crate::error::attach_day;
+use crate::input::normalize_for;
+use crate::params::{check_params, Param};
+use crate::strategy::Strategy;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+/// Answer and per-phase timings of a single part.
+#[derive(Clone, Debug)]
+pub struct Run {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// A day's puzzle, split into phases so the runner can time each of them.
+///
+/// Every day crate implements this for the part it solves and leaves the other part at its
+/// default, which reports that the part isn't implemented.
+pub trait Solution {
+    const DAY: u8;
+
+    /// The puzzle constants this part can be run with other values of; see [`param`].
+    ///
+    /// [`param`]: crate::param
+    const PARAMS: &'static [Param] = &[];
+
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error>;
+
+    fn part1(_parsed: &Self::Parsed) -> Result<Answer, Error> {
+        Err(Error::msg("part 1 not implemented"))
+    }
+
+    fn part2(_parsed: &Self::Parsed) -> Result<Answer, Error> {
+        Err(Error::msg("part 2 not implemented"))
+    }
+
+    /// Every way this crate has of solving `part`, for [`cross_check`] to compare, starting
+    /// with the one the part itself uses. Empty when there is only the one.
+    ///
+    /// [`cross_check`]: crate::cross_check
+    fn strategies(_part: Part) -> Vec<Strategy<Self::Parsed>> {
+        Vec::new()
+    }
+}
+
+impl Part {
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.number().fmt(f)
+    }
+}
+
+pub fn run<S: Solution>(part: Part, input: &str) -> Result<Run, Error> {
+    check_params::<S>()?;
+    let input = normalize_for(S::DAY, part, input);
+    let start = Instant::now();
+    let parsed = S::parse(&input).map_err(|e| attach_day(e, S::DAY))?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = solve_parsed::<S>(part, &parsed)?;
+    let solve_time = start.elapsed();
+
+    Ok(Run {
+        answer,
+        parse_time,
+        solve_time,
+    })
+}
+
+/// The stack a thread running a solver gets, far more than the 2 MiB threads get by default, so
+/// no input the solvers accept can overflow it.
+pub const SOLVER_STACK_SIZE: usize = 256 << 20;
+
+/// Spawns a thread with [`SOLVER_STACK_SIZE`] of stack to run a solver on.
+pub fn spawn_solver<F, T>(f: F) -> io::Result<JoinHandle<T>>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    thread::Builder::new()
+        .stack_size(SOLVER_STACK_SIZE)
+        .spawn(f)
+}
+
+pub fn solve<S: Solution>(part: Part, input: &str) -> Result<String, Error> {
+    check_params::<S>()?;
+    let input = normalize_for(S::DAY, part, input);
+    let parsed = S::parse(&input).map_err(|e| attach_day(e, S::DAY))?;
+    solve_parsed::<S>(part, &parsed)
+}
+
+pub fn solve_reader<S: Solution, R: BufRead>(part: Part, reader: R) -> Result<String, Error> {
+    solve::<S>(part, &read_to_string(reader)?)
+}
+
+pub(crate) fn solve_parsed<S: Solution>(part: Part, parsed: &S::Parsed) -> Result<String, Error> {
+    let answer = match part {
+        Part::One => S::part1(parsed),
+        Part::Two => S::part2(parsed),
+    };
+    answer
+        .map(|answer| answer.to_string())
+        .map_err(|e| attach_day(e, S::DAY))
+}
@@ -0,0 +1,141 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Error;
+
+use crate::answer::Answer;
+use crate::error::attach_day;
+use crate::input::normalize_for;
+use crate::params::check_params;
+use crate::solution::{solve_parsed, Part, Solution};
+
+/// One way of solving a part, for days that have more than one; see [`Solution::strategies`].
+pub struct Strategy<P> {
+    pub name: &'static str,
+    pub solve: fn(&P) -> Result<Answer, Error>,
+}
+
+/// What one strategy answered in a [`cross_check`], and how long it took.
+#[derive(Clone, Debug)]
+pub struct StrategyRun {
+    pub name: &'static str,
+    pub answer: String,
+    pub solve_time: Duration,
+}
+
+/// Parses `input` once and solves `part` of `S` with each of its strategies, or with the part
+/// alone if it has none. Fails if a strategy does, or if they don't all agree, listing what each
+/// of them answered.
+pub fn cross_check<S: Solution>(part: Part, input: &str) -> Result<Vec<StrategyRun>, Error> {
+    check_params::<S>()?;
+    let input = normalize_for(S::DAY, part, input);
+    let parsed = S::parse(&input).map_err(|e| attach_day(e, S::DAY))?;
+
+    let strategies = S::strategies(part);
+    if strategies.is_empty() {
+        let start = Instant::now();
+        let answer = solve_parsed::<S>(part, &parsed)?;
+        return Ok(vec![StrategyRun {
+            name: "default",
+            answer,
+            solve_time: start.elapsed(),
+        }]);
+    }
+
+    let mut runs = Vec::with_capacity(strategies.len());
+    for strategy in strategies {
+        let start = Instant::now();
+        let answer = (strategy.solve)(&parsed)
+            .map(|answer| answer.to_string())
+            .map_err(|e| attach_day(e, S::DAY))
+            .map_err(|e| e.context(format!("strategy {:?}", strategy.name)))?;
+        runs.push(StrategyRun {
+            name: strategy.name,
+            answer,
+            solve_time: start.elapsed(),
+        });
+    }
+
+    if runs.iter().any(|run| run.answer != runs[0].answer) {
+        return Err(Error::msg(format!(
+            "strategies disagree: {}",
+            runs.iter()
+                .map(|run| format!("{} = {} ({:.2?})", run.name, run.answer, run.solve_time))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+    Ok(runs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sums numbers, and has a strategy that gets it wrong when one of them is 13.
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+
+        type Parsed = Vec<u64>;
+
+        fn parse(input: &str) -> Result<Self::Parsed, Error> {
+            Ok(input
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part1(numbers: &Self::Parsed) -> Result<Answer, Error> {
+            Ok(numbers.iter().sum::<u64>().into())
+        }
+
+        fn strategies(part: Part) -> Vec<Strategy<Self::Parsed>> {
+            match part {
+                Part::One => vec![
+                    Strategy {
+                        name: "iterator",
+                        solve: Self::part1,
+                    },
+                    Strategy {
+                        name: "unlucky",
+                        solve: |numbers| {
+                            let unlucky = numbers.iter().filter(|n| **n != 13).sum::<u64>();
+                            Ok(unlucky.into())
+                        },
+                    },
+                ],
+                Part::Two => Vec::new(),
+            }
+        }
+    }
+
+    #[test]
+    fn agreeing_strategies_report_each_answer() {
+        let runs = cross_check::<Sum>(Part::One, "1 2 3\n").unwrap();
+        let answers = runs
+            .iter()
+            .map(|run| (run.name, run.answer.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(answers, [("iterator", "6"), ("unlucky", "6")]);
+    }
+
+    #[test]
+    fn disagreeing_strategies_fail() {
+        let error = cross_check::<Sum>(Part::One, "1 13\n")
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.starts_with("strategies disagree: iterator = 14 ("),
+            "{}",
+            error
+        );
+        assert!(error.contains(", unlucky = 1 ("), "{}", error);
+    }
+
+    #[test]
+    fn parts_without_strategies_run_alone() {
+        let runs = cross_check::<Sum>(Part::Two, "1\n").unwrap_err();
+        assert_eq!(runs.to_string(), "part 2 not implemented");
+    }
+}
@@ -0,0 +1,98 @@
+//! Every solved (day, part) pair in one table, for the runner, the C interface and anything
+//! else that needs to go through all of them.
+
+use anyhow::Error;
+use aoc2023_common::{cross_check, run, Param, Part, Report, Run, Solution, StrategyRun};
+
+/// One solvable (day, part) pair.
+pub struct Entry {
+    pub day: u8,
+    pub part: Part,
+    /// The puzzle constants the part can be run with other values of.
+    pub params: &'static [Param],
+    run: fn(Part, &str) -> Result<Run, Error>,
+    cross_check: fn(Part, &str) -> Result<Vec<StrategyRun>, Error>,
+}
+
+impl Entry {
+    const fn new<S: Solution>(part: Part) -> Self {
+        Self {
+            day: S::DAY,
+            part,
+            params: S::PARAMS,
+            run: run::<S>,
+            cross_check: cross_check::<S>,
+        }
+    }
+
+    pub fn run(&self, input: &str) -> Result<Run, Error> {
+        (self.run)(self.part, input)
+    }
+
+    /// Runs the part with each of its strategies, failing if they disagree.
+    pub fn cross_check(&self, input: &str) -> Result<Vec<StrategyRun>, Error> {
+        (self.cross_check)(self.part, input)
+    }
+
+    /// The name of the crate that solves the part, which is also its directory.
+    pub fn crate_name(&self) -> String {
+        match self.part {
+            Part::One => format!("aoc2023_{:02}", self.day),
+            Part::Two => format!("aoc2023_{:02}_2", self.day),
+        }
+    }
+
+    /// Runs the part like [`Entry::run`], capturing its diagnostics into a report.
+    pub fn report(&self, input: &str) -> Report {
+        Report::from_run(self.day, self.part, || self.run(input))
+    }
+}
+
+pub static ENTRIES: &[Entry] = &[
+    Entry::new::<aoc2023_01::Day01>(Part::One),
+    Entry::new::<aoc2023_01_2::Day01>(Part::Two),
+    Entry::new::<aoc2023_02::Day02>(Part::One),
+    Entry::new::<aoc2023_02_2::Day02>(Part::Two),
+    Entry::new::<aoc2023_03::Day03>(Part::One),
+    Entry::new::<aoc2023_03_2::Day03>(Part::Two),
+    Entry::new::<aoc2023_04::Day04>(Part::One),
+    Entry::new::<aoc2023_04_2::Day04>(Part::Two),
+    Entry::new::<aoc2023_05::Day05>(Part::One),
+    Entry::new::<aoc2023_05_2::Day05>(Part::Two),
+    Entry::new::<aoc2023_06::Day06>(Part::One),
+    Entry::new::<aoc2023_06_2::Day06>(Part::Two),
+    Entry::new::<aoc2023_07::Day07>(Part::One),
+    Entry::new::<aoc2023_07_2::Day07>(Part::Two),
+    Entry::new::<aoc2023_08::Day08>(Part::One),
+    Entry::new::<aoc2023_08_2::Day08>(Part::Two),
+    Entry::new::<aoc2023_09::Day09>(Part::One),
+    Entry::new::<aoc2023_09_2::Day09>(Part::Two),
+    Entry::new::<aoc2023_10::Day10>(Part::One),
+    Entry::new::<aoc2023_10_2::Day10>(Part::Two),
+    Entry::new::<aoc2023_11::Day11>(Part::One),
+    Entry::new::<aoc2023_11_2::Day11>(Part::Two),
+    Entry::new::<aoc2023_12::Day12>(Part::One),
+    Entry::new::<aoc2023_12_2::Day12>(Part::Two),
+    Entry::new::<aoc2023_13::Day13>(Part::One),
+    Entry::new::<aoc2023_13_2::Day13>(Part::Two),
+    Entry::new::<aoc2023_14::Day14>(Part::One),
+    Entry::new::<aoc2023_14_2::Day14>(Part::Two),
+    Entry::new::<aoc2023_15::Day15>(Part::One),
+    Entry::new::<aoc2023_15_2::Day15>(Part::Two),
+    Entry::new::<aoc2023_16::Day16>(Part::One),
+    Entry::new::<aoc2023_16_2::Day16>(Part::Two),
+    Entry::new::<aoc2023_17::Day17>(Part::One),
+    Entry::new::<aoc2023_17_2::Day17>(Part::Two),
+    Entry::new::<aoc2023_18::Day18>(Part::One),
+    Entry::new::<aoc2023_18_2::Day18>(Part::Two),
+    Entry::new::<aoc2023_19::Day19>(Part::One),
+    Entry::new::<aoc2023_19_2::Day19>(Part::Two),
+    Entry::new::<aoc2023_20::Day20>(Part::One),
+    Entry::new::<aoc2023_20_2::Day20>(Part::Two),
+];
+
+pub fn find(day: u8, part: Option<Part>) -> impl Iterator<Item = &'static Entry> {
+    ENTRIES
+        .iter()
+        .filter(move |entry| entry.day == day && part.is_none_or(|part| entry.part == part))
+}
@@ -0,0 +1,85 @@
+use std::fmt::Write;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+const MAP_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// An almanac whose seeds are given as ranges, as part 2 reads them.
+#[derive(Clone, Debug)]
+pub struct Almanac {
+    /// `(start, length)` of each seed range.
+    pub seed_ranges: Vec<(u64, u64)>,
+    /// `(destination, source, length)` of each entry, one list per map. Sources within a map
+    /// never overlap.
+    pub maps: Vec<Vec<(u64, u64, u64)>>,
+}
+
+impl Almanac {
+    /// The puzzle input with seeds as `start length` pairs, for part 2.
+    pub fn with_seed_ranges(&self) -> String {
+        let seeds = self
+            .seed_ranges
+            .iter()
+            .map(|(start, length)| format!("{} {}", start, length))
+            .collect::<Vec<_>>();
+        self.render(&seeds)
+    }
+
+    /// The puzzle input with every seed of every range listed one by one, for part 1.
+    pub fn with_seed_list(&self) -> String {
+        let seeds = self
+            .seed_ranges
+            .iter()
+            .flat_map(|(start, length)| *start..start + length)
+            .map(|seed| seed.to_string())
+            .collect::<Vec<_>>();
+        self.render(&seeds)
+    }
+
+    fn render(&self, seeds: &[String]) -> String {
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+        for (name, entries) in MAP_NAMES.iter().zip(self.maps.iter()) {
+            write!(input, "\n{} map:\n", name).unwrap();
+            for (destination, source, length) in entries {
+                writeln!(input, "{} {} {}", destination, source, length).unwrap();
+            }
+        }
+        input
+    }
+}
+
+/// Almanacs with a few short seed ranges and up to seven small maps.
+pub fn almanac() -> impl Strategy<Value = Almanac> {
+    (
+        vec((0u64..100, 1u64..10), 1..4),
+        vec(map_entries(), 1..=MAP_NAMES.len()),
+    )
+        .prop_map(|(seed_ranges, maps)| Almanac { seed_ranges, maps })
+}
+
+/// Entries with disjoint sources, in shuffled order.
+fn map_entries() -> impl Strategy<Value = Vec<(u64, u64, u64)>> {
+    vec((0u64..20, 1u64..20, 0u64..120), 0..5)
+        .prop_map(|ranges| {
+            let mut source = 0;
+            ranges
+                .into_iter()
+                .map(|(gap, length, destination)| {
+                    source += gap;
+                    let entry = (destination, source, length);
+                    source += length;
+                    entry
+                })
+                .collect::<Vec<_>>()
+        })
+        .prop_shuffle()
+}
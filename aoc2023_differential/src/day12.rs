@@ -0,0 +1,90 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::{select, Index};
+
+/// One row of springs with the damaged group sizes it has to match.
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub status: Vec<u8>,
+    pub groups: Vec<usize>,
+}
+
+impl Record {
+    /// The record as a line of puzzle input.
+    pub fn line(&self) -> String {
+        let groups = self
+            .groups
+            .iter()
+            .map(|g| g.to_string())
+            .collect::<Vec<_>>();
+        format!(
+            "{} {}",
+            String::from_utf8_lossy(&self.status),
+            groups.join(",")
+        )
+    }
+}
+
+/// Records short enough to try every assignment. Most have at least one arrangement, but some
+/// have their groups changed afterwards or picked at random, so usually have none, to exercise
+/// the pruning that gives up on those early.
+pub fn record() -> impl Strategy<Value = Record> {
+    prop_oneof![
+        2 => satisfiable_record(),
+        1 => (satisfiable_record(), any::<Index>(), 0..4u8).prop_map(
+            |(mut record, index, change)| {
+                perturb(&mut record.groups, index, change);
+                record
+            }
+        ),
+        1 => (vec(select(b".#?".to_vec()), 1..14), vec(1..6usize, 0..6))
+            .prop_map(|(status, groups)| Record { status, groups }),
+    ]
+}
+
+/// Grows, shrinks, adds or removes the group at `index`; shrinking a group of one removes it.
+fn perturb(groups: &mut Vec<usize>, index: Index, change: u8) {
+    if groups.is_empty() {
+        groups.push(1);
+        return;
+    }
+    let i = index.index(groups.len());
+    match change {
+        0 => groups[i] += 1,
+        1 if groups[i] > 1 => groups[i] -= 1,
+        2 => groups.insert(i, 1),
+        _ => {
+            groups.remove(i);
+        }
+    }
+}
+
+/// Records built from a known row of springs, so there is always at least one arrangement, with
+/// some springs then hidden behind `?`.
+fn satisfiable_record() -> impl Strategy<Value = Record> {
+    vec((any::<bool>(), prop::bool::weighted(0.4)), 1..14).prop_map(|springs| {
+        let mut groups = Vec::new();
+        let mut run = 0;
+        for (damaged, _) in springs.iter() {
+            if *damaged {
+                run += 1;
+            } else if run != 0 {
+                groups.push(run);
+                run = 0;
+            }
+        }
+        if run != 0 {
+            groups.push(run);
+        }
+
+        let status = springs
+            .iter()
+            .map(|(damaged, hidden)| match (damaged, hidden) {
+                (_, true) => b'?',
+                (true, false) => b'#',
+                (false, false) => b'.',
+            })
+            .collect();
+        Record { status, groups }
+    })
+}
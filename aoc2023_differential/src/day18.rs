@@ -0,0 +1,87 @@
+use std::fmt::Write;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+/// A dig plan tracing a simple loop, built from side-by-side columns of trench. Each column
+/// spans the rows `top..=bottom` and overlaps its neighbours, so the loop never touches itself.
+#[derive(Clone, Debug)]
+pub struct DigPlan {
+    /// `(width, top, bottom)` of each column, left to right.
+    pub columns: Vec<(u8, i64, i64)>,
+}
+
+impl DigPlan {
+    /// The moves around the loop, clockwise from the top left corner, with no empty moves and no
+    /// two in a row going the same way.
+    pub fn moves(&self) -> Vec<(char, u32)> {
+        let mut moves = Vec::new();
+        let mut push = |direction: char, steps: i64| {
+            let (direction, steps) = match (direction, steps) {
+                (_, 0) => return,
+                ('V', steps) if steps > 0 => ('D', steps),
+                ('V', steps) => ('U', -steps),
+                (direction, steps) => (direction, steps),
+            };
+            let steps = steps as u32;
+            match moves.last_mut() {
+                Some((last, last_steps)) if *last == direction => *last_steps += steps,
+                _ => moves.push((direction, steps)),
+            }
+        };
+
+        for (i, (width, top, _)) in self.columns.iter().enumerate() {
+            push('R', i64::from(*width));
+            if let Some((_, next_top, _)) = self.columns.get(i + 1) {
+                push('V', next_top - top);
+            }
+        }
+        let (_, last_top, last_bottom) = self.columns[self.columns.len() - 1];
+        push('V', last_bottom - last_top);
+        for (i, (width, _, bottom)) in self.columns.iter().enumerate().rev() {
+            push('L', i64::from(*width));
+            if i > 0 {
+                push('V', self.columns[i - 1].2 - bottom);
+            }
+        }
+        let (_, first_top, first_bottom) = self.columns[0];
+        push('V', first_top - first_bottom);
+
+        moves
+    }
+
+    /// The puzzle input, with each move written both plainly for part 1 and encoded in the
+    /// color for part 2.
+    pub fn input(&self) -> String {
+        let mut input = String::new();
+        for (direction, steps) in self.moves() {
+            let digit = match direction {
+                'R' => 0,
+                'D' => 1,
+                'L' => 2,
+                _ => 3,
+            };
+            writeln!(input, "{} {} (#{:05x}{})", direction, steps, steps, digit).unwrap();
+        }
+        input
+    }
+}
+
+/// Dig plans of up to eight columns, each a few blocks wide and tall.
+pub fn dig_plan() -> impl Strategy<Value = DigPlan> {
+    vec((1u8..5, 0i64..8, 1i64..8), 1..8).prop_map(|columns| {
+        let mut fixed = Vec::<(u8, i64, i64)>::with_capacity(columns.len());
+        for (width, top, height) in columns {
+            let mut bottom = top + height;
+            let mut top = top;
+            if let Some((_, previous_top, previous_bottom)) = fixed.last() {
+                // share at least one row with the previous column, so the loop's top and
+                // bottom edges never meet
+                top = top.min(previous_bottom - 1);
+                bottom = bottom.max(previous_top + 1);
+            }
+            fixed.push((width, top, bottom));
+        }
+        DigPlan { columns: fixed }
+    })
+}
@@ -0,0 +1,33 @@
+//! Differential testing of days that have a naive and an optimized solver.
+//!
+//! Each module generates small random puzzles for one day, written out in whatever form each
+//! solver reads. The tests in `tests/` feed them to both solvers and use [`agree`] to check that
+//! the answers match; proptest then shrinks any disagreement down to a minimal puzzle.
+
+use std::fmt::Debug;
+
+use anyhow::Error;
+use proptest::test_runner::TestCaseError;
+
+pub mod day05;
+pub mod day12;
+pub mod day18;
+
+/// Checks that two solvers gave the same answer for `input`, treating an error from either as a
+/// disagreement.
+pub fn agree<T>(
+    input: &str,
+    naive: Result<T, Error>,
+    optimized: Result<T, Error>,
+) -> Result<(), TestCaseError>
+where
+    T: Debug + PartialEq,
+{
+    match (naive, optimized) {
+        (Ok(naive), Ok(optimized)) if naive == optimized => Ok(()),
+        (naive, optimized) => Err(TestCaseError::fail(format!(
+            "naive {:?} but optimized {:?} for input:\n{}",
+            naive, optimized, input
+        ))),
+    }
+}
@@ -0,0 +1,30 @@
+use anyhow::Error;
+use aoc2023_differential::{agree, day05, day12, day18};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn day05_ranges_match_seed_by_seed(almanac in day05::almanac()) {
+        let input = almanac.with_seed_ranges();
+        agree(
+            &input,
+            aoc2023_05::solve(&almanac.with_seed_list()),
+            aoc2023_05_2::solve(&input),
+        )?;
+    }
+
+    #[test]
+    fn day12_memoized_matches_brute_force(record in day12::record()) {
+        agree(
+            &record.line(),
+            Ok(aoc2023_12::count_combos(&record.status, &record.groups)),
+            aoc2023_12_2::count_combos(&record.status, &record.groups).map_err(Error::from),
+        )?;
+    }
+
+    #[test]
+    fn day18_compressed_matches_full_grid(plan in day18::dig_plan()) {
+        let input = plan.input();
+        agree(&input, aoc2023_18::solve(&input), aoc2023_18_2::solve(&input))?;
+    }
+}
@@ -0,0 +1,166 @@
+//! Writes `aoc2023.h` to `OUT_DIR` from the `pub const`s and `extern "C"` functions in
+//! `src/lib.rs`, keeping their doc comments. Only the handful of types the interface uses are
+//! understood. The source tree is left alone, so read-only checkouts still build;
+//! `tests/header.rs` checks that `include/aoc2023.h`, the copy C code includes, is up to date.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+const SOURCE: &str = "src/lib.rs";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", SOURCE);
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let source = fs::read_to_string(SOURCE).expect("can't read src/lib.rs");
+    let header = header(&source);
+    let path = PathBuf::from(env::var_os("OUT_DIR").expect("cargo sets OUT_DIR")).join("aoc2023.h");
+    // rewriting an unchanged header would make C builds that depend on it rebuild for nothing
+    if fs::read_to_string(&path).ok().as_deref() != Some(header.as_str()) {
+        fs::write(&path, header).expect("can't write aoc2023.h");
+    }
+}
+
+fn header(source: &str) -> String {
+    let mut declarations = Vec::new();
+    let mut docs = Vec::new();
+    let mut lines = source.lines();
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+        if let Some(doc) = line.strip_prefix("///") {
+            docs.push(doc.strip_prefix(' ').unwrap_or(doc));
+            continue;
+        }
+        if line.starts_with("#[") {
+            continue;
+        }
+        if let Some(constant) = line.strip_prefix("pub const ") {
+            declarations.push(comment(&docs) + &define(constant));
+        } else if line.starts_with("pub extern \"C\" fn ")
+            || line.starts_with("pub unsafe extern \"C\" fn ")
+        {
+            // the signature runs on to the opening brace of the body
+            let mut signature = line.to_owned();
+            while !signature.ends_with('{') {
+                let next = lines.next().expect("unfinished signature");
+                signature.push(' ');
+                signature.push_str(next.trim());
+            }
+            declarations.push(comment(&docs) + &function(&signature));
+        }
+        docs.clear();
+    }
+
+    format!(
+        "/* Generated from src/lib.rs by build.rs; edit those instead. */
+
+#ifndef AOC2023_H
+#define AOC2023_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern \"C\" {{
+#endif
+
+{}
+#ifdef __cplusplus
+}}
+#endif
+
+#endif /* AOC2023_H */
+",
+        declarations.join("\n")
+    )
+}
+
+/// `docs` as a C comment, with the Markdown headings and code spans plain.
+fn comment(docs: &[&str]) -> String {
+    if docs.is_empty() {
+        return String::new();
+    }
+    let mut comment = "/**\n".to_owned();
+    for doc in docs {
+        let doc = doc.strip_prefix("# ").unwrap_or(doc).replace('`', "");
+        if doc.is_empty() {
+            comment.push_str(" *\n");
+        } else {
+            comment.push_str(&format!(" * {}\n", doc));
+        }
+    }
+    comment + " */\n"
+}
+
+/// `NAME: TYPE = VALUE;` as a `#define`.
+fn define(constant: &str) -> String {
+    let (name, rest) = constant.split_once(':').expect("constant without a type");
+    let (_, value) = rest.split_once('=').expect("constant without a value");
+    format!(
+        "#define {} {}\n",
+        name.trim(),
+        value.trim().trim_end_matches(';')
+    )
+}
+
+/// `pub [unsafe] extern "C" fn name(args) [-> type] {` as a prototype.
+fn function(signature: &str) -> String {
+    let (_, rest) = signature.split_once("fn ").expect("not a function");
+    let (name, rest) = rest.split_once('(').expect("function without arguments");
+    let (args, rest) = rest.rsplit_once(')').expect("unclosed arguments");
+    let returns = rest
+        .trim()
+        .trim_end_matches('{')
+        .trim()
+        .strip_prefix("->")
+        .map_or("void".to_owned(), |ty| c_type(ty.trim()));
+
+    let args = args
+        .split(',')
+        .map(str::trim)
+        .filter(|arg| !arg.is_empty())
+        .map(|arg| {
+            let (name, ty) = arg.split_once(':').expect("argument without a type");
+            let ty = c_type(ty.trim());
+            if ty.ends_with('*') {
+                format!("{}{}", ty, name.trim())
+            } else {
+                format!("{} {}", ty, name.trim())
+            }
+        })
+        .collect::<Vec<_>>();
+    let args = if args.is_empty() {
+        "void".to_owned()
+    } else {
+        args.join(", ")
+    };
+    format!("{} {}({});\n", returns, name.trim(), args)
+}
+
+fn c_type(ty: &str) -> String {
+    if let Some(pointee) = ty.strip_prefix("*const ") {
+        return format!("const {}", pointer_to(pointee));
+    }
+    if let Some(pointee) = ty.strip_prefix("*mut ") {
+        return pointer_to(pointee);
+    }
+    match ty {
+        "c_char" => "char",
+        "u8" => "uint8_t",
+        "u32" => "uint32_t",
+        "i32" => "int32_t",
+        "usize" => "size_t",
+        _ => panic!("no C type for {}", ty),
+    }
+    .to_owned()
+}
+
+fn pointer_to(pointee: &str) -> String {
+    let pointee = c_type(pointee);
+    if pointee.ends_with('*') {
+        format!("{}*", pointee)
+    } else {
+        format!("{} *", pointee)
+    }
+}
@@ -0,0 +1,76 @@
+/* Generated from src/lib.rs by build.rs; edit those instead. */
+
+#ifndef AOC2023_H
+#define AOC2023_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * The version of this interface, changed whenever a declaration changes.
+ */
+#define AOC_ABI_VERSION 1
+
+/**
+ * The part was solved, and the answer is in *answer.
+ */
+#define AOC_OK 0
+
+/**
+ * The solver failed, probably on a malformed input, and the reason is in *error.
+ */
+#define AOC_ERROR 1
+
+/**
+ * The day or part isn't solved, or an argument is unusable; *error says which.
+ */
+#define AOC_INVALID_ARGUMENT 2
+
+/**
+ * The version of the interface the library was built with, AOC_ABI_VERSION.
+ */
+uint32_t aoc_abi_version(void);
+
+/**
+ * Writes the solved days in ascending order to days, as many as fit in capacity, and
+ * returns how many there are in all. Every solved day has both parts.
+ *
+ * Safety
+ *
+ * days must point to capacity writable bytes, and may only be null if capacity is 0.
+ */
+size_t aoc_days(uint8_t *days, size_t capacity);
+
+/**
+ * Solves part of day for the input_len bytes of UTF-8 at input.
+ *
+ * Returns AOC_OK with the answer in *answer, or another status with the reason in
+ * *error; the other is set to null. Either string is the caller's, to free with
+ * aoc_string_free. A solver that panics counts as failing. The solver runs on a thread of its
+ * own with a large stack, so deeply nested inputs can't overflow the caller's.
+ *
+ * Safety
+ *
+ * input must point to input_len readable bytes, and may only be null if input_len is
+ * 0. answer and error must point to writable pointers.
+ */
+int32_t aoc_solve(uint8_t day, uint8_t part, const char *input, size_t input_len, char **answer, char **error);
+
+/**
+ * Frees a string returned by aoc_solve. Does nothing given null.
+ *
+ * Safety
+ *
+ * string must be null or a string from aoc_solve that hasn't been freed yet.
+ */
+void aoc_string_free(char *string);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC2023_H */
@@ -0,0 +1,144 @@
+//! The solvers as a shared library with a C interface, for tools that can't link Rust. The
+//! declarations are in `include/aoc2023.h`, so C code can build with `-I aoc2023_ffi/include`,
+//! and `tests/every_day.c` shows how to call them. The build generates the header afresh in its
+//! `OUT_DIR`, and a test fails until the checked-in copy is updated to match.
+//!
+//! Strings returned to the caller belong to it, and must go back through [`aoc_string_free`].
+
+use std::ffi::{c_char, CString};
+use std::{ptr, slice, str};
+
+use aoc2023_common::{panic_message, spawn_solver, Part};
+use aoc2023_days::ENTRIES;
+
+/// The version of this interface, changed whenever a declaration changes.
+pub const AOC_ABI_VERSION: u32 = 1;
+
+/// The part was solved, and the answer is in `*answer`.
+pub const AOC_OK: i32 = 0;
+/// The solver failed, probably on a malformed input, and the reason is in `*error`.
+pub const AOC_ERROR: i32 = 1;
+/// The day or part isn't solved, or an argument is unusable; `*error` says which.
+pub const AOC_INVALID_ARGUMENT: i32 = 2;
+
+/// The version of the interface the library was built with, `AOC_ABI_VERSION`.
+#[no_mangle]
+pub extern "C" fn aoc_abi_version() -> u32 {
+    AOC_ABI_VERSION
+}
+
+/// Writes the solved days in ascending order to `days`, as many as fit in `capacity`, and
+/// returns how many there are in all. Every solved day has both parts.
+///
+/// # Safety
+///
+/// `days` must point to `capacity` writable bytes, and may only be null if `capacity` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_days(days: *mut u8, capacity: usize) -> usize {
+    let mut solved = ENTRIES.iter().map(|entry| entry.day).collect::<Vec<_>>();
+    solved.dedup();
+    if !days.is_null() {
+        // SAFETY: the caller promises `capacity` bytes at `days`
+        let days = unsafe { slice::from_raw_parts_mut(days, capacity) };
+        for (slot, day) in days.iter_mut().zip(solved.iter()) {
+            *slot = *day;
+        }
+    }
+    solved.len()
+}
+
+/// Solves `part` of `day` for the `input_len` bytes of UTF-8 at `input`.
+///
+/// Returns `AOC_OK` with the answer in `*answer`, or another status with the reason in
+/// `*error`; the other is set to null. Either string is the caller's, to free with
+/// `aoc_string_free`. A solver that panics counts as failing. The solver runs on a thread of its
+/// own with a large stack, so deeply nested inputs can't overflow the caller's.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes, and may only be null if `input_len` is
+/// 0. `answer` and `error` must point to writable pointers.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const c_char,
+    input_len: usize,
+    answer: *mut *mut c_char,
+    error: *mut *mut c_char,
+) -> i32 {
+    if answer.is_null() || error.is_null() {
+        return AOC_INVALID_ARGUMENT;
+    }
+    // SAFETY: not null, and the caller promises both point to writable pointers
+    let (answer, error) = unsafe { (&mut *answer, &mut *error) };
+    *answer = ptr::null_mut();
+    *error = ptr::null_mut();
+
+    let input = if input_len == 0 {
+        &[][..]
+    } else if input.is_null() {
+        return fail(error, AOC_INVALID_ARGUMENT, "input is null".to_owned());
+    } else {
+        // SAFETY: the caller promises `input_len` bytes at `input`
+        unsafe { slice::from_raw_parts(input.cast::<u8>(), input_len) }
+    };
+    let Ok(input) = str::from_utf8(input) else {
+        return fail(error, AOC_INVALID_ARGUMENT, "input isn't UTF-8".to_owned());
+    };
+    let Some(entry) = Part::from_number(part).and_then(|part| {
+        ENTRIES
+            .iter()
+            .find(|entry| entry.day == day && entry.part == part)
+    }) else {
+        return fail(
+            error,
+            AOC_INVALID_ARGUMENT,
+            format!("day {} part {} not solved", day, part),
+        );
+    };
+
+    let input = input.to_owned();
+    let solver = match spawn_solver(move || entry.run(&input)) {
+        Ok(solver) => solver,
+        Err(e) => return fail(error, AOC_ERROR, format!("can't start a solver: {}", e)),
+    };
+    match solver.join() {
+        Ok(Ok(run)) => {
+            *answer = into_c_string(run.answer);
+            AOC_OK
+        }
+        Ok(Err(e)) => fail(error, AOC_ERROR, format!("{:#}", e)),
+        Err(payload) => fail(
+            error,
+            AOC_ERROR,
+            format!("panicked: {}", panic_message(&*payload)),
+        ),
+    }
+}
+
+/// Frees a string returned by `aoc_solve`. Does nothing given null.
+///
+/// # Safety
+///
+/// `string` must be null or a string from `aoc_solve` that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        // SAFETY: the caller promises it came from `into_c_string` and is freed only once
+        drop(unsafe { CString::from_raw(string) });
+    }
+}
+
+/// Sets `*error` to `message` and returns `status`.
+fn fail(error: &mut *mut c_char, status: i32, message: String) -> i32 {
+    *error = into_c_string(message);
+    status
+}
+
+/// Hands `text` over to C, escaping any NULs, which would end it early.
+fn into_c_string(text: String) -> *mut c_char {
+    CString::new(text.replace('\0', "\\0"))
+        .expect("NULs are escaped")
+        .into_raw()
+}
@@ -0,0 +1,41 @@
+//! Builds `every_day.c` against the shared library and the checked-in header, and runs it.
+
+#![cfg(unix)]
+
+use std::env::current_exe;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn every_day_from_c() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // the shared library is built next to the test binaries
+    let exe = current_exe().unwrap();
+    let lib_dir = exe.parent().unwrap();
+    let program = lib_dir.join("every_day_c");
+
+    let status = Command::new("cc")
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror", "-I"])
+        .arg(crate_dir.join("include"))
+        .arg(crate_dir.join("tests/every_day.c"))
+        .arg("-L")
+        .arg(lib_dir)
+        .arg("-laoc2023_ffi")
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .expect("can't run cc");
+    assert!(status.success(), "every_day.c doesn't compile");
+
+    let output = Command::new(&program)
+        .arg(crate_dir.parent().unwrap())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "40 parts ok\n");
+}
@@ -0,0 +1,179 @@
+/* Solves the first example of every part through the C interface and checks the answers, then
+ * checks the errors. Run with the workspace directory, where the examples are, as the only
+ * argument; exits non-zero if anything is wrong. */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "aoc2023.h"
+
+struct example {
+    uint8_t day;
+    uint8_t part;
+    const char *path;
+    const char *answer;
+};
+
+static const struct example EXAMPLES[] = {
+    {1, 1, "aoc2023_01/tests/examples/example.txt", "142"},
+    {1, 2, "aoc2023_01_2/tests/examples/example.txt", "281"},
+    {2, 1, "aoc2023_02/tests/examples/example.txt", "8"},
+    {2, 2, "aoc2023_02_2/tests/examples/example.txt", "2286"},
+    {3, 1, "aoc2023_03/tests/examples/example.txt", "4361"},
+    {3, 2, "aoc2023_03_2/tests/examples/example.txt", "467835"},
+    {4, 1, "aoc2023_04/tests/examples/example.txt", "13"},
+    {4, 2, "aoc2023_04_2/tests/examples/example.txt", "30"},
+    {5, 1, "aoc2023_05/tests/examples/example.txt", "35"},
+    {5, 2, "aoc2023_05_2/tests/examples/example.txt", "46"},
+    {6, 1, "aoc2023_06/tests/examples/example.txt", "288"},
+    {6, 2, "aoc2023_06_2/tests/examples/example.txt", "71503"},
+    {7, 1, "aoc2023_07/tests/examples/example.txt", "6440"},
+    {7, 2, "aoc2023_07_2/tests/examples/example.txt", "5905"},
+    {8, 1, "aoc2023_08/tests/examples/example1.txt", "2"},
+    {8, 2, "aoc2023_08_2/tests/examples/example.txt", "6"},
+    {9, 1, "aoc2023_09/tests/examples/example.txt", "114"},
+    {9, 2, "aoc2023_09_2/tests/examples/example.txt", "2"},
+    {10, 1, "aoc2023_10/tests/examples/example1.txt", "4"},
+    {10, 2, "aoc2023_10_2/tests/examples/example1.txt", "4"},
+    {11, 1, "aoc2023_11/tests/examples/example.txt", "374"},
+    {11, 2, "aoc2023_11_2/tests/examples/example.txt", "82000210"},
+    {12, 1, "aoc2023_12/tests/examples/example.txt", "21"},
+    {12, 2, "aoc2023_12_2/tests/examples/example.txt", "525152"},
+    {13, 1, "aoc2023_13/tests/examples/example.txt", "405"},
+    {13, 2, "aoc2023_13_2/tests/examples/example.txt", "400"},
+    {14, 1, "aoc2023_14/tests/examples/example.txt", "136"},
+    {14, 2, "aoc2023_14_2/tests/examples/example.txt", "64"},
+    {15, 1, "aoc2023_15/tests/examples/example.txt", "1320"},
+    {15, 2, "aoc2023_15_2/tests/examples/example.txt", "145"},
+    {16, 1, "aoc2023_16/tests/examples/example.txt", "46"},
+    {16, 2, "aoc2023_16_2/tests/examples/example.txt", "51"},
+    {17, 1, "aoc2023_17/tests/examples/example.txt", "102"},
+    {17, 2, "aoc2023_17_2/tests/examples/example1.txt", "94"},
+    {18, 1, "aoc2023_18/tests/examples/example.txt", "62"},
+    {18, 2, "aoc2023_18_2/tests/examples/example.txt", "952408144115"},
+    {19, 1, "aoc2023_19/tests/examples/example.txt", "19114"},
+    {19, 2, "aoc2023_19_2/tests/examples/example.txt", "167409079868000"},
+    {20, 1, "aoc2023_20/tests/examples/example1.txt", "32000000"},
+    {20, 2, "aoc2023_20_2/tests/examples/counter.txt", "4"},
+};
+
+static int failures = 0;
+
+static void fail(const char *what, uint8_t day, uint8_t part, const char *detail) {
+    fprintf(stderr, "day %u part %u: %s: %s\n", day, part, what, detail ? detail : "(null)");
+    failures++;
+}
+
+/* Reads the whole file at root/path into a buffer the caller frees, or returns NULL. */
+static char *read_file(const char *root, const char *path, size_t *len) {
+    char full[4096];
+    snprintf(full, sizeof full, "%s/%s", root, path);
+    FILE *file = fopen(full, "rb");
+    if (!file) {
+        return NULL;
+    }
+    size_t capacity = 4096;
+    char *buffer = malloc(capacity);
+    *len = 0;
+    size_t got;
+    while (buffer && (got = fread(buffer + *len, 1, capacity - *len, file)) > 0) {
+        *len += got;
+        if (*len == capacity) {
+            capacity *= 2;
+            char *bigger = realloc(buffer, capacity);
+            if (!bigger) {
+                free(buffer);
+            }
+            buffer = bigger;
+        }
+    }
+    fclose(file);
+    return buffer;
+}
+
+static void check_days(void) {
+    if (aoc_abi_version() != AOC_ABI_VERSION) {
+        fail("abi version", 0, 0, "differs from the header's");
+    }
+    size_t count = aoc_days(NULL, 0);
+    uint8_t days[64];
+    if (count != 20 || aoc_days(days, sizeof days) != count) {
+        fail("days", 0, 0, "expected 20 of them");
+        return;
+    }
+    for (size_t i = 0; i < count; i++) {
+        if (days[i] != i + 1) {
+            fail("days", days[i], 0, "out of order");
+        }
+    }
+}
+
+static void check_example(const char *root, const struct example *example) {
+    size_t len;
+    char *input = read_file(root, example->path, &len);
+    if (!input) {
+        fail("can't read", example->day, example->part, example->path);
+        return;
+    }
+    char *answer = NULL;
+    char *error = NULL;
+    int32_t status = aoc_solve(example->day, example->part, input, len, &answer, &error);
+    if (status != AOC_OK) {
+        fail("failed", example->day, example->part, error);
+    } else if (strcmp(answer, example->answer) != 0) {
+        fail("wrong answer", example->day, example->part, answer);
+    } else if (error) {
+        fail("error alongside the answer", example->day, example->part, error);
+    }
+    aoc_string_free(answer);
+    aoc_string_free(error);
+    free(input);
+}
+
+static void check_errors(void) {
+    char *answer = NULL;
+    char *error = NULL;
+    const char bad[] = "1abc\nxyz\n";
+
+    if (aoc_solve(1, 1, bad, sizeof bad - 1, &answer, &error) != AOC_ERROR || answer ||
+        !error || !strstr(error, "xyz")) {
+        fail("malformed input", 1, 1, error);
+    }
+    aoc_string_free(error);
+
+    if (aoc_solve(26, 1, "", 0, &answer, &error) != AOC_INVALID_ARGUMENT || answer ||
+        !error || strcmp(error, "day 26 part 1 not solved") != 0) {
+        fail("unsolved day", 26, 1, error);
+    }
+    aoc_string_free(error);
+
+    if (aoc_solve(1, 1, "\xff\n", 2, &answer, &error) != AOC_INVALID_ARGUMENT || answer ||
+        !error) {
+        fail("invalid UTF-8", 1, 1, error);
+    }
+    aoc_string_free(error);
+
+    if (aoc_solve(1, 1, "", 0, NULL, NULL) != AOC_INVALID_ARGUMENT) {
+        fail("null outputs", 1, 1, NULL);
+    }
+    aoc_string_free(NULL);
+}
+
+int main(int argc, char **argv) {
+    if (argc != 2) {
+        fprintf(stderr, "usage: %s <workspace directory>\n", argv[0]);
+        return 2;
+    }
+    check_days();
+    for (size_t i = 0; i < sizeof EXAMPLES / sizeof EXAMPLES[0]; i++) {
+        check_example(argv[1], &EXAMPLES[i]);
+    }
+    check_errors();
+    if (failures) {
+        fprintf(stderr, "%d failure(s)\n", failures);
+        return 1;
+    }
+    printf("%zu parts ok\n", sizeof EXAMPLES / sizeof EXAMPLES[0]);
+    return 0;
+}
@@ -0,0 +1,15 @@
+//! Checks that the checked-in `include/aoc2023.h` matches the one the build generates.
+
+use std::path::Path;
+
+#[test]
+fn checked_in_header_is_current() {
+    let generated = Path::new(env!("OUT_DIR")).join("aoc2023.h");
+    let checked_in = Path::new(env!("CARGO_MANIFEST_DIR")).join("include/aoc2023.h");
+    assert!(
+        std::fs::read_to_string(&checked_in).ok() == std::fs::read_to_string(&generated).ok(),
+        "{} is out of date; copy it from {}",
+        checked_in.display(),
+        generated.display()
+    );
+}
@@ -0,0 +1,46 @@
+//! Solves a large input through the C interface from a thread with little stack to spare.
+
+use std::ffi::{c_char, CStr};
+use std::ptr;
+use std::thread;
+
+use aoc2023_ffi::{aoc_solve, aoc_string_free, AOC_OK};
+use aoc2023_generate::{find, Options};
+
+#[test]
+fn large_input_on_a_small_stack() {
+    let puzzle = find(16)
+        .unwrap()
+        .generate(0, &Options::new().set("size", 700))
+        .unwrap();
+
+    let status = thread::Builder::new()
+        .stack_size(64 << 10)
+        .spawn(move || {
+            let mut answer = ptr::null_mut::<c_char>();
+            let mut error = ptr::null_mut::<c_char>();
+            // SAFETY: the input is a live string and both outputs are writable
+            let status = unsafe {
+                aoc_solve(
+                    16,
+                    1,
+                    puzzle.input.as_ptr().cast(),
+                    puzzle.input.len(),
+                    &mut answer,
+                    &mut error,
+                )
+            };
+            // SAFETY: whichever is set came from aoc_solve, and the other is null
+            unsafe {
+                assert!(error.is_null(), "{:?}", CStr::from_ptr(error));
+                assert!(!CStr::from_ptr(answer).to_bytes().is_empty());
+                aoc_string_free(answer);
+                aoc_string_free(error);
+            }
+            status
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+    assert_eq!(status, AOC_OK);
+}
@@ -0,0 +1,59 @@
+use anyhow::Error;
+use rand::Rng as _;
+
+use crate::{Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 1,
+    params: &[
+        Param {
+            name: "size",
+            default: "1000",
+            help: "number of lines",
+        },
+        Param {
+            name: "length",
+            default: "40",
+            help: "longest line",
+        },
+        Param {
+            name: "words",
+            default: "0.2",
+            help: "chance of each piece being a spelled-out digit",
+        },
+    ],
+    generate,
+};
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 1000)?;
+    let length = options.get("length", 40usize)?.max(1);
+    let words = options.get("words", 0.2)?;
+
+    let mut input = String::new();
+    for _ in 0..size {
+        let target = rng.gen_range(1..=length);
+        let mut line = String::new();
+        while line.len() < target {
+            if rng.gen_bool(words) {
+                line.push_str(WORDS[rng.gen_range(0..WORDS.len())]);
+            } else if rng.gen_bool(0.2) {
+                line.push(rng.gen_range(b'1'..=b'9') as char);
+            } else {
+                line.push(rng.gen_range(b'a'..=b'z') as char);
+            }
+        }
+        // part 1 needs a digit on every line
+        if !line.bytes().any(|b| b.is_ascii_digit()) {
+            let at = rng.gen_range(0..=line.len());
+            line.insert(at, rng.gen_range(b'1'..=b'9') as char);
+        }
+        input.push_str(&line);
+        input.push('\n');
+    }
+    Ok(Puzzle::new(input))
+}
@@ -0,0 +1,51 @@
+use anyhow::Error;
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+use crate::{Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 2,
+    params: &[
+        Param {
+            name: "size",
+            default: "100",
+            help: "number of games",
+        },
+        Param {
+            name: "reveals",
+            default: "6",
+            help: "most handfuls shown in a game",
+        },
+        Param {
+            name: "cubes",
+            default: "20",
+            help: "most cubes of one color in a handful",
+        },
+    ],
+    generate,
+};
+
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 100)?;
+    let reveals = options.get("reveals", 6usize)?.max(1);
+    let cubes = options.get("cubes", 20u32)?.max(1);
+
+    let mut input = String::new();
+    for game in 1..=size {
+        let handfuls = (0..rng.gen_range(1..=reveals))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                colors.shuffle(rng);
+                let shown = rng.gen_range(1..=colors.len());
+                colors[..shown]
+                    .iter()
+                    .map(|color| format!("{} {}", rng.gen_range(1..=cubes), color))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+        input.push_str(&format!("Game {}: {}\n", game, handfuls.join("; ")));
+    }
+    Ok(Puzzle::new(input))
+}
@@ -0,0 +1,55 @@
+use anyhow::Error;
+use rand::Rng as _;
+
+use crate::{grid_text, Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 3,
+    params: &[
+        Param {
+            name: "size",
+            default: "140",
+            help: "width and height of the schematic",
+        },
+        Param {
+            name: "numbers",
+            default: "0.1",
+            help: "chance of a number starting at each free cell",
+        },
+        Param {
+            name: "symbols",
+            default: "0.05",
+            help: "chance of a symbol at each free cell",
+        },
+    ],
+    generate,
+};
+
+const SYMBOLS: &[u8] = b"*#+$/@=%-&";
+
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 140usize)?;
+    let numbers = options.get("numbers", 0.1)?;
+    let symbols = options.get("symbols", 0.05)?;
+
+    let mut rows = vec![vec![b'.'; size]; size];
+    for row in rows.iter_mut() {
+        let mut x = 0;
+        while x < size {
+            if rng.gen_bool(numbers) {
+                let digits = rng.gen_range(1..=3).min(size - x);
+                for cell in row[x..x + digits].iter_mut() {
+                    *cell = rng.gen_range(b'0'..=b'9');
+                }
+                // keep numbers on the same row apart
+                x += digits + 1;
+            } else {
+                if rng.gen_bool(symbols) {
+                    row[x] = SYMBOLS[rng.gen_range(0..SYMBOLS.len())];
+                }
+                x += 1;
+            }
+        }
+    }
+    Ok(Puzzle::new(grid_text(size, size, |x, y| rows[y][x])))
+}
@@ -0,0 +1,77 @@
+use anyhow::Error;
+use rand::seq::index::sample;
+use rand::Rng as _;
+
+use crate::{Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 4,
+    params: &[
+        Param {
+            name: "size",
+            default: "200",
+            help: "number of cards",
+        },
+        Param {
+            name: "winning",
+            default: "10",
+            help: "winning numbers per card",
+        },
+        Param {
+            name: "have",
+            default: "25",
+            help: "numbers you have per card",
+        },
+        Param {
+            name: "wins",
+            default: "0.3",
+            help: "chance of a card having any matches; keep it low or part 2's copies explode",
+        },
+    ],
+    generate,
+};
+
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 200)?;
+    let winning = options.get("winning", 10usize)?.clamp(1, 49);
+    let have = options.get("have", 25usize)?.clamp(1, 99 - winning);
+    let wins = options.get("wins", 0.3)?;
+
+    let width = size.to_string().len();
+    let mut input = String::new();
+    for card in 1..=size {
+        // numbers 1..=99, the winning ones first
+        let numbers = sample(rng, 99, winning + have)
+            .into_iter()
+            .map(|i| i + 1)
+            .collect::<Vec<_>>();
+        let matches = if rng.gen_bool(wins) {
+            rng.gen_range(1..=winning.min(have).min(5))
+        } else {
+            0
+        };
+        let winning_numbers = &numbers[..winning];
+        let mut have_numbers = numbers[winning..].to_vec();
+        have_numbers[..matches].copy_from_slice(&winning_numbers[..matches]);
+        let last = have_numbers.len() - 1;
+        for i in 0..have_numbers.len() {
+            have_numbers.swap(i, rng.gen_range(i..=last));
+        }
+
+        let list = |numbers: &[usize]| {
+            numbers
+                .iter()
+                .map(|n| format!("{:2}", n))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        input.push_str(&format!(
+            "Card {:width$}: {} | {}\n",
+            card,
+            list(winning_numbers),
+            list(&have_numbers),
+            width = width
+        ));
+    }
+    Ok(Puzzle::new(input))
+}
@@ -0,0 +1,83 @@
+use anyhow::Error;
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+use crate::{Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 5,
+    params: &[
+        Param {
+            name: "size",
+            default: "30",
+            help: "entries per map",
+        },
+        Param {
+            name: "seeds",
+            default: "10",
+            help: "seed ranges, read as pairs of seeds by part 1",
+        },
+        Param {
+            name: "span",
+            default: "4294967296",
+            help: "numbers are below this",
+        },
+    ],
+    generate,
+};
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 30u64)?.max(1);
+    let seeds = options.get("seeds", 10)?;
+    let span = options.get("span", 1u64 << 32)?.max(size * 2);
+
+    let seeds = (0..seeds)
+        .map(|_| {
+            let start = rng.gen_range(0..span);
+            let length = rng.gen_range(1..=(span - start).min(span / 10).max(1));
+            format!("{} {}", start, length)
+        })
+        .collect::<Vec<_>>();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for map in MAPS {
+        input.push_str(&format!("\n{} map:\n", map));
+
+        // cut 0..span into pieces and move them around, so the map is a bijection like the real
+        // ones, then leave a few pieces out so they map to themselves
+        let mut cuts = (0..size * 2)
+            .map(|_| rng.gen_range(1..span))
+            .collect::<Vec<_>>();
+        cuts.extend([0, span]);
+        cuts.sort_unstable();
+        cuts.dedup();
+        let mut pieces = cuts
+            .windows(2)
+            .map(|w| (w[0], w[1] - w[0]))
+            .collect::<Vec<_>>();
+        pieces.shuffle(rng);
+        let mut destination = 0;
+        let mut entries = Vec::new();
+        for (source, length) in pieces {
+            entries.push((destination, source, length));
+            destination += length;
+        }
+        entries.shuffle(rng);
+        entries.truncate(size as usize);
+
+        for (destination, source, length) in entries {
+            input.push_str(&format!("{} {} {}\n", destination, source, length));
+        }
+    }
+    Ok(Puzzle::new(input))
+}
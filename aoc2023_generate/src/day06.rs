@@ -0,0 +1,55 @@
+use anyhow::Error;
+use rand::Rng as _;
+
+use crate::{Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 6,
+    params: &[
+        Param {
+            name: "size",
+            default: "4",
+            help: "number of races; part 2 joins their numbers, so keep it small",
+        },
+        Param {
+            name: "time",
+            default: "100",
+            help: "longest race",
+        },
+    ],
+    generate,
+};
+
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 4)?;
+    let time = options.get("time", 100u64)?.max(2);
+
+    let mut times = Vec::new();
+    let mut distances = Vec::new();
+    for _ in 0..size {
+        let t = rng.gen_range(2..=time);
+        // the best you can do is hold for half the race
+        let best = (t / 2) * (t - t / 2);
+        times.push(t.to_string());
+        distances.push(rng.gen_range(0..best).to_string());
+    }
+
+    let width = times
+        .iter()
+        .chain(distances.iter())
+        .map(String::len)
+        .max()
+        .unwrap_or(0);
+    let row = |numbers: &[String]| {
+        numbers
+            .iter()
+            .map(|n| format!("{:>width$}", n, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+    };
+    Ok(Puzzle::new(format!(
+        "Time:      {}\nDistance:  {}\n",
+        row(&times),
+        row(&distances)
+    )))
+}
@@ -0,0 +1,40 @@
+use anyhow::Error;
+use rand::Rng as _;
+
+use crate::{Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 7,
+    params: &[
+        Param {
+            name: "size",
+            default: "1000",
+            help: "number of hands",
+        },
+        Param {
+            name: "bid",
+            default: "1000",
+            help: "largest bid",
+        },
+    ],
+    generate,
+};
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 1000)?;
+    let bid = options.get("bid", 1000u64)?.max(1);
+
+    let mut input = String::new();
+    for _ in 0..size {
+        // draw from fewer ranks now and then, so strong hands show up too
+        let ranks = rng.gen_range(1..=CARDS.len());
+        let offset = rng.gen_range(0..=CARDS.len() - ranks);
+        for _ in 0..5 {
+            input.push(CARDS[offset + rng.gen_range(0..ranks)] as char);
+        }
+        input.push_str(&format!(" {}\n", rng.gen_range(1..=bid)));
+    }
+    Ok(Puzzle::new(input))
+}
@@ -0,0 +1,133 @@
+use anyhow::Error;
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+use crate::{lcm, primes_in, Generator, Options, Param, Puzzle, Rng};
+
+/// Three-letter names ending in neither `A` nor `Z`, for the nodes between starts and ends.
+const NAMES: usize = 26 * 26 * 24;
+
+pub const GENERATOR: Generator = Generator {
+    day: 8,
+    params: &[
+        Param {
+            name: "size",
+            default: "40",
+            help: "number of instructions",
+        },
+        Param {
+            name: "ghosts",
+            default: "6",
+            help: "number of starting nodes",
+        },
+        Param {
+            name: "cycle",
+            default: "80",
+            help: "each ghost loops after the instructions times a distinct prime below this",
+        },
+    ],
+    generate,
+};
+
+/// Builds a network where every ghost walks a loop of its own, reaching its `Z` node exactly
+/// once per loop. Each loop is the instructions times a distinct prime long, so part 1 is the
+/// first ghost's loop and part 2 is the least common multiple of all of them.
+///
+/// Every node name is three letters, as part 1 walks from `AAA` to `ZZZ`, which leaves room for
+/// [`NAMES`] nodes between the starts and ends.
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 40usize)?.max(1);
+    let ghosts = options.get("ghosts", 6usize)?.max(1);
+    let cycle = options.get("cycle", 80u64)?;
+    // the last two-letter prefix would give a ghost the first ghost's ZZZ
+    if ghosts >= 26 * 26 {
+        return Err(Error::msg(format!(
+            "at most {} ghosts fit in three-letter names",
+            26 * 26 - 1
+        )));
+    }
+
+    let mut primes = primes_in(2..cycle);
+    if primes.len() < ghosts {
+        return Err(Error::msg(format!(
+            "only {} primes below {}, need one per ghost",
+            primes.len(),
+            cycle
+        )));
+    }
+    primes.shuffle(rng);
+    primes.truncate(ghosts);
+
+    let instructions = (0..size)
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect::<String>();
+
+    let between = size.saturating_mul(primes.iter().sum::<u64>() as usize);
+    if between > NAMES {
+        return Err(Error::msg(format!(
+            "{} instructions times primes {:?} need {} nodes, more than the {} three-letter \
+             names; lower size or cycle",
+            size, primes, between, NAMES
+        )));
+    }
+    let mut indices = (0..between).collect::<Vec<_>>();
+    indices.shuffle(rng);
+    let mut indices = indices.into_iter();
+
+    let mut nodes = Vec::new();
+    let mut part2 = 1;
+    for (ghost, prime) in primes.iter().enumerate() {
+        let steps = size * *prime as usize;
+        part2 = lcm(part2, steps as u64);
+
+        // path[0] starts the loop and path[steps] ends it, with the rest in between; the first
+        // ghost goes from AAA to ZZZ for part 1
+        let prefix = letters(ghost, 2);
+        let (start, end) = if ghost == 0 {
+            ("AAA".to_owned(), "ZZZ".to_owned())
+        } else {
+            (format!("{}A", prefix), format!("{}Z", prefix))
+        };
+        let mut path = vec![start];
+        for _ in 1..steps {
+            let i = indices.next().expect("enough names for every node");
+            path.push(format!(
+                "{}{}",
+                letters(i / 24, 2),
+                (b'B' + (i % 24) as u8) as char
+            ));
+        }
+        path.push(end);
+
+        for (step, node) in path.iter().enumerate() {
+            // the end leads back round, to the same node the start leads to
+            let next = &path[if step == steps { 1 } else { step + 1 }];
+            // ghosts never take the other turn, so it can go anywhere on the loop
+            let other = &path[rng.gen_range(1..path.len())];
+            let (left, right) = if instructions.as_bytes()[step % size] == b'L' {
+                (next, other)
+            } else {
+                (other, next)
+            };
+            nodes.push(format!("{} = ({}, {})", node, left, right));
+        }
+    }
+    let part1 = size as u64 * primes[0];
+    nodes.shuffle(rng);
+
+    Ok(Puzzle {
+        input: format!("{}\n\n{}\n", instructions, nodes.join("\n")),
+        part1: Some(part1.to_string()),
+        part2: Some(part2.to_string()),
+    })
+}
+
+/// `n` written as `length` capital letters, so 0 is all `A`s.
+fn letters(mut n: usize, length: usize) -> String {
+    let mut text = vec![b'A'; length];
+    for b in text.iter_mut().rev() {
+        *b = b'A' + (n % 26) as u8;
+        n /= 26;
+    }
+    String::from_utf8(text).expect("letters are ASCII")
+}
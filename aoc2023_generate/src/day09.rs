@@ -0,0 +1,65 @@
+use anyhow::Error;
+use rand::Rng as _;
+
+use crate::{Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 9,
+    params: &[
+        Param {
+            name: "size",
+            default: "200",
+            help: "number of histories",
+        },
+        Param {
+            name: "length",
+            default: "21",
+            help: "values per history",
+        },
+        Param {
+            name: "degree",
+            default: "6",
+            help: "highest degree of the polynomials behind the histories",
+        },
+    ],
+    generate,
+};
+
+/// Each history is a polynomial with small coefficients, sampled at 0, 1, 2, ... Its degree is
+/// below the length, so the differences always reach zero and the answers are the polynomial's
+/// values just past either end.
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 200)?;
+    let length = options.get("length", 21i128)?.max(2);
+    let degree = options.get("degree", 6usize)?.min(length as usize - 2);
+
+    let mut input = String::new();
+    let mut part1 = 0i128;
+    let mut part2 = 0i128;
+    for _ in 0..size {
+        let coefficients = (0..=rng.gen_range(0..=degree))
+            .map(|_| rng.gen_range(-5i128..=5))
+            .collect::<Vec<_>>();
+        let value = |x: i128| {
+            coefficients
+                .iter()
+                .rev()
+                .fold(0, |total, coefficient| total * x + coefficient)
+        };
+
+        let values = (0..length)
+            .map(|x| value(x).to_string())
+            .collect::<Vec<_>>();
+        input.push_str(&values.join(" "));
+        input.push('\n');
+        part1 += value(length);
+        part2 += value(-1);
+    }
+
+    let fits = |answer: i128| i64::try_from(answer).map(|answer| answer.to_string());
+    Ok(Puzzle {
+        input,
+        part1: Some(fits(part1).map_err(|_| Error::msg("part 1 overflows i64"))?),
+        part2: Some(fits(part2).map_err(|_| Error::msg("part 2 overflows i64"))?),
+    })
+}
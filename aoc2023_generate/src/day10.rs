@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+
+use anyhow::Error;
+use rand::Rng as _;
+
+use crate::{grid_text, Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 10,
+    params: &[
+        Param {
+            name: "size",
+            default: "140",
+            help: "width and height of the maze",
+        },
+        Param {
+            name: "coverage",
+            default: "0.5",
+            help: "share of the maze the loop winds through",
+        },
+        Param {
+            name: "junk",
+            default: "0.7",
+            help: "chance of a tile off the loop being a pipe rather than ground",
+        },
+    ],
+    generate,
+};
+
+const PIPES: &[u8] = b"|-LJ7F";
+
+/// Grows a random tree on every other tile and runs the loop around its outline. The outline of
+/// a tree is a single loop that never touches itself, and its length and the area inside it give
+/// both answers.
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 140usize)?.max(3);
+    let coverage = options.get("coverage", 0.5)?;
+    let junk = options.get("junk", 0.7)?;
+
+    // the tree's nodes sit on even cells of a (2 * nodes - 1) square, with the edges between
+    // them on the cells in between; the loop runs along the corners of those cells, which take
+    // twice as many tiles again
+    let nodes = (size + 1) / 4;
+    let cells = 2 * nodes - 1;
+    let mut occupied = vec![false; cells * cells];
+    let mut in_tree = vec![false; nodes * nodes];
+
+    let root = (rng.gen_range(0..nodes), rng.gen_range(0..nodes));
+    in_tree[root.1 * nodes + root.0] = true;
+    occupied[2 * root.1 * cells + 2 * root.0] = true;
+    let mut frontier = vec![root];
+    let mut grown = 1;
+    let wanted = ((nodes * nodes) as f64 * coverage).ceil() as usize;
+    while grown < wanted && !frontier.is_empty() {
+        let i = rng.gen_range(0..frontier.len());
+        let (x, y) = frontier[i];
+        let next = [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .iter()
+            .filter_map(|(dx, dy)| {
+                let nx = x.checked_add_signed(*dx).filter(|nx| *nx < nodes)?;
+                let ny = y.checked_add_signed(*dy).filter(|ny| *ny < nodes)?;
+                (!in_tree[ny * nodes + nx]).then_some((nx, ny))
+            })
+            .collect::<Vec<_>>();
+        if next.is_empty() {
+            frontier.swap_remove(i);
+            continue;
+        }
+        let (nx, ny) = next[rng.gen_range(0..next.len())];
+        in_tree[ny * nodes + nx] = true;
+        occupied[2 * ny * cells + 2 * nx] = true;
+        occupied[(y + ny) * cells + (x + nx)] = true;
+        frontier.push((nx, ny));
+        grown += 1;
+    }
+
+    // each side of an occupied cell facing an empty one is part of the loop
+    let mut links = BTreeMap::<(usize, usize), Vec<(usize, usize)>>::new();
+    let mut link = |a: (usize, usize), b: (usize, usize)| {
+        links.entry(a).or_default().push(b);
+        links.entry(b).or_default().push(a);
+    };
+    let is_occupied = |x: isize, y: isize| {
+        x >= 0
+            && y >= 0
+            && (x as usize) < cells
+            && (y as usize) < cells
+            && occupied[y as usize * cells + x as usize]
+    };
+    for y in 0..cells {
+        for x in 0..cells {
+            if !occupied[y * cells + x] {
+                continue;
+            }
+            let (sx, sy) = (x as isize, y as isize);
+            if !is_occupied(sx, sy - 1) {
+                link((x, y), (x + 1, y));
+            }
+            if !is_occupied(sx + 1, sy) {
+                link((x + 1, y), (x + 1, y + 1));
+            }
+            if !is_occupied(sx, sy + 1) {
+                link((x, y + 1), (x + 1, y + 1));
+            }
+            if !is_occupied(sx - 1, sy) {
+                link((x, y), (x, y + 1));
+            }
+        }
+    }
+
+    // walk the loop's corners in order, then spread them out to every other tile so the loop
+    // has room inside it
+    let start = *links.keys().next().expect("the tree has a cell");
+    let mut corners = vec![start];
+    let mut previous = start;
+    let mut current = links[&start][0];
+    while current != start {
+        corners.push(current);
+        let neighbours = &links[&current];
+        let next = if neighbours[0] == previous {
+            neighbours[1]
+        } else {
+            neighbours[0]
+        };
+        previous = current;
+        current = next;
+    }
+    let path = corners
+        .iter()
+        .zip(corners.iter().cycle().skip(1))
+        .flat_map(|(a, b)| [(2 * a.0, 2 * a.1), (a.0 + b.0, a.1 + b.1)])
+        .collect::<Vec<_>>();
+
+    // lay the pipes and measure the loop
+    let mut tiles = vec![b'.'; size * size];
+    let length = path.len() as i64;
+    let mut twice_area = 0i64;
+    for (i, current) in path.iter().enumerate() {
+        let previous = path[(i + path.len() - 1) % path.len()];
+        let next = path[(i + 1) % path.len()];
+        tiles[current.1 * size + current.0] = pipe(*current, previous, next);
+        twice_area += current.0 as i64 * next.1 as i64 - next.0 as i64 * current.1 as i64;
+    }
+
+    // clutter the rest with pipes that go nowhere, keeping the start's neighbours clear so it
+    // only connects to the loop
+    let loop_tiles = tiles.iter().map(|t| *t != b'.').collect::<Vec<_>>();
+    for (tile, on_loop) in tiles.iter_mut().zip(loop_tiles.iter()) {
+        if !on_loop && rng.gen_bool(junk) {
+            *tile = PIPES[rng.gen_range(0..PIPES.len())];
+        }
+    }
+    let (sx, sy) = path[rng.gen_range(0..path.len())];
+    tiles[sy * size + sx] = b'S';
+    for (dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0)] {
+        if let (Some(x), Some(y)) = (sx.checked_add_signed(dx), sy.checked_add_signed(dy)) {
+            if x < size && y < size && !loop_tiles[y * size + x] {
+                tiles[y * size + x] = b'.';
+            }
+        }
+    }
+
+    // Pick's theorem gives the tiles inside from the area and the tiles on the loop
+    let inside = twice_area.abs() / 2 - length / 2 + 1;
+    Ok(Puzzle {
+        input: grid_text(size, size, |x, y| tiles[y * size + x]),
+        part1: Some((length / 2).to_string()),
+        part2: Some(inside.to_string()),
+    })
+}
+
+/// The pipe at `at` joining `a` and `b`.
+fn pipe(at: (usize, usize), a: (usize, usize), b: (usize, usize)) -> u8 {
+    let side = |other: (usize, usize)| match (
+        other.0 as isize - at.0 as isize,
+        other.1 as isize - at.1 as isize,
+    ) {
+        (0, -1) => 0,
+        (1, 0) => 1,
+        (0, 1) => 2,
+        _ => 3,
+    };
+    match (side(a).min(side(b)), side(a).max(side(b))) {
+        (0, 2) => b'|',
+        (1, 3) => b'-',
+        (0, 1) => b'L',
+        (0, 3) => b'J',
+        (2, 3) => b'7',
+        _ => b'F',
+    }
+}
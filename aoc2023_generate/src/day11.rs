@@ -0,0 +1,42 @@
+use anyhow::Error;
+use rand::Rng as _;
+
+use crate::{grid_text, Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 11,
+    params: &[
+        Param {
+            name: "size",
+            default: "140",
+            help: "width and height of the image",
+        },
+        Param {
+            name: "galaxies",
+            default: "0.02",
+            help: "chance of a galaxy at each spot",
+        },
+        Param {
+            name: "empty",
+            default: "0.05",
+            help: "share of rows and columns kept empty so they expand",
+        },
+    ],
+    generate,
+};
+
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 140usize)?;
+    let galaxies = options.get("galaxies", 0.02)?;
+    let empty = options.get("empty", 0.05)?;
+
+    let empty_rows = (0..size).map(|_| rng.gen_bool(empty)).collect::<Vec<_>>();
+    let empty_columns = (0..size).map(|_| rng.gen_bool(empty)).collect::<Vec<_>>();
+    Ok(Puzzle::new(grid_text(size, size, |x, y| {
+        if !empty_rows[y] && !empty_columns[x] && rng.gen_bool(galaxies) {
+            b'#'
+        } else {
+            b'.'
+        }
+    })))
+}
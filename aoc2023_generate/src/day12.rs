@@ -0,0 +1,59 @@
+use anyhow::Error;
+use rand::Rng as _;
+
+use crate::{Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 12,
+    params: &[
+        Param {
+            name: "size",
+            default: "1000",
+            help: "number of rows",
+        },
+        Param {
+            name: "length",
+            default: "20",
+            help: "longest row of springs",
+        },
+        Param {
+            name: "hidden",
+            default: "0.4",
+            help: "chance of a spring's condition being unknown",
+        },
+    ],
+    generate,
+};
+
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 1000)?;
+    let length = options.get("length", 20usize)?.max(1);
+    let hidden = options.get("hidden", 0.4)?;
+
+    let mut input = String::new();
+    for _ in 0..size {
+        // start from a real row, so there is always an arrangement, then hide some of it
+        let mut damaged = (0..rng.gen_range(1..=length))
+            .map(|_| rng.gen_bool(0.5))
+            .collect::<Vec<_>>();
+        let at = rng.gen_range(0..damaged.len());
+        damaged[at] = true;
+
+        let groups = damaged
+            .split(|d| !d)
+            .filter(|run| !run.is_empty())
+            .map(|run| run.len().to_string())
+            .collect::<Vec<_>>();
+        for d in damaged {
+            input.push(match (d, rng.gen_bool(hidden)) {
+                (_, true) => '?',
+                (true, false) => '#',
+                (false, false) => '.',
+            });
+        }
+        input.push(' ');
+        input.push_str(&groups.join(","));
+        input.push('\n');
+    }
+    Ok(Puzzle::new(input))
+}
@@ -0,0 +1,123 @@
+use anyhow::Error;
+use rand::Rng as _;
+
+use crate::{Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 13,
+    params: &[
+        Param {
+            name: "size",
+            default: "100",
+            help: "number of patterns",
+        },
+        Param {
+            name: "width",
+            default: "17",
+            help: "widest and tallest pattern",
+        },
+    ],
+    generate,
+};
+
+/// Each pattern mirrors across a column and across a row, then has one rock flipped where only
+/// the row reflection sees it. The column is then the only reflection and the row the only one a
+/// smudge away, which gives both answers.
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 100)?;
+    let width = options.get("width", 17usize)?.max(5);
+
+    let mut patterns = Vec::new();
+    let mut part1 = 0;
+    let mut part2 = 0;
+    while patterns.len() < size {
+        let w = rng.gen_range(5..=width);
+        let h = rng.gen_range(3..=width);
+        let mut grid = (0..h)
+            .map(|_| (0..w).map(|_| rng.gen_bool(0.5)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        // reflect between columns a - 1 and a, away from the middle so some columns are left
+        // out, and between rows b - 1 and b
+        let a = rng.gen_range(1..w);
+        if 2 * a == w {
+            continue;
+        }
+        let b = rng.gen_range(1..h);
+        for row in grid.iter_mut() {
+            for x in a..w.min(2 * a) {
+                row[x] = row[2 * a - 1 - x];
+            }
+        }
+        for y in b..h.min(2 * b) {
+            grid[y] = grid[2 * b - 1 - y].clone();
+        }
+
+        let reach = a.min(w - a);
+        let x = if a - reach > 0 {
+            rng.gen_range(0..a - reach)
+        } else {
+            rng.gen_range(a + reach..w)
+        };
+        let reach = b.min(h - b);
+        let y = rng.gen_range(b - reach..b + reach);
+        grid[y][x] = !grid[y][x];
+
+        let columns = (1..w)
+            .map(|c| column_differences(&grid, c))
+            .collect::<Vec<_>>();
+        let rows = (1..h)
+            .map(|r| row_differences(&grid, r))
+            .collect::<Vec<_>>();
+        let count = |n| {
+            columns
+                .iter()
+                .chain(rows.iter())
+                .filter(|d| **d == n)
+                .count()
+        };
+        if count(0) != 1 || count(1) != 1 || columns[a - 1] != 0 || rows[b - 1] != 1 {
+            continue;
+        }
+
+        part1 += a;
+        part2 += 100 * b;
+        patterns.push(
+            grid.iter()
+                .map(|row| row.iter().map(|r| if *r { '#' } else { '.' }).collect())
+                .collect::<Vec<String>>()
+                .join("\n"),
+        );
+    }
+
+    Ok(Puzzle {
+        input: patterns.join("\n\n") + "\n",
+        part1: Some(part1.to_string()),
+        part2: Some(part2.to_string()),
+    })
+}
+
+/// Cells that differ from their mirror image across the line left of column `c`.
+fn column_differences(grid: &[Vec<bool>], c: usize) -> usize {
+    let w = grid[0].len();
+    grid.iter()
+        .map(|row| {
+            (0..c.min(w - c))
+                .filter(|i| row[c - 1 - i] != row[c + i])
+                .count()
+        })
+        .sum()
+}
+
+/// Cells that differ from their mirror image across the line above row `r`.
+fn row_differences(grid: &[Vec<bool>], r: usize) -> usize {
+    (0..r.min(grid.len() - r))
+        .map(|i| {
+            grid[r - 1 - i]
+                .iter()
+                .zip(grid[r + i].iter())
+                .filter(|(a, b)| a != b)
+                .count()
+        })
+        .sum()
+}
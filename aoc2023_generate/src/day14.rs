@@ -0,0 +1,43 @@
+use anyhow::Error;
+use rand::Rng as _;
+
+use crate::{grid_text, Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 14,
+    params: &[
+        Param {
+            name: "size",
+            default: "100",
+            help: "width and height of the platform",
+        },
+        Param {
+            name: "round",
+            default: "0.2",
+            help: "chance of a rounded rock at each spot",
+        },
+        Param {
+            name: "cube",
+            default: "0.1",
+            help: "chance of a cube-shaped rock at each spot",
+        },
+    ],
+    generate,
+};
+
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 100usize)?;
+    let round = options.get("round", 0.2)?;
+    let cube = options.get("cube", 0.1)?;
+
+    Ok(Puzzle::new(grid_text(size, size, |_, _| {
+        let roll = rng.gen::<f64>();
+        if roll < round {
+            b'O'
+        } else if roll < round + cube {
+            b'#'
+        } else {
+            b'.'
+        }
+    })))
+}
@@ -0,0 +1,45 @@
+use anyhow::Error;
+use rand::Rng as _;
+
+use crate::{Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 15,
+    params: &[
+        Param {
+            name: "size",
+            default: "4000",
+            help: "number of steps",
+        },
+        Param {
+            name: "labels",
+            default: "500",
+            help: "distinct lens labels",
+        },
+    ],
+    generate,
+};
+
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 4000)?;
+    let labels = options.get("labels", 500)?.max(1);
+
+    let labels = (0..labels)
+        .map(|_| {
+            (0..rng.gen_range(2..=6))
+                .map(|_| rng.gen_range(b'a'..=b'z') as char)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    let steps = (0..size)
+        .map(|_| {
+            let label = &labels[rng.gen_range(0..labels.len())];
+            if rng.gen_bool(0.3) {
+                format!("{}-", label)
+            } else {
+                format!("{}={}", label, rng.gen_range(1..=9))
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(Puzzle::new(steps.join(",") + "\n"))
+}
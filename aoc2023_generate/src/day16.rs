@@ -0,0 +1,36 @@
+use anyhow::Error;
+use rand::Rng as _;
+
+use crate::{grid_text, Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 16,
+    params: &[
+        Param {
+            name: "size",
+            default: "110",
+            help: "width and height of the contraption",
+        },
+        Param {
+            name: "mirrors",
+            default: "0.1",
+            help: "chance of a mirror or splitter at each tile",
+        },
+    ],
+    generate,
+};
+
+const DEVICES: &[u8] = b"/\\|-";
+
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 110usize)?.max(1);
+    let mirrors = options.get("mirrors", 0.1)?;
+
+    Ok(Puzzle::new(grid_text(size, size, |_, _| {
+        if rng.gen_bool(mirrors) {
+            DEVICES[rng.gen_range(0..DEVICES.len())]
+        } else {
+            b'.'
+        }
+    })))
+}
@@ -0,0 +1,30 @@
+use anyhow::Error;
+use rand::Rng as _;
+
+use crate::{grid_text, Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 17,
+    params: &[
+        Param {
+            name: "size",
+            default: "141",
+            help: "width and height of the city",
+        },
+        Param {
+            name: "height",
+            default: "size",
+            help: "height of the city, if it differs from the width",
+        },
+    ],
+    generate,
+};
+
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 141usize)?.max(1);
+    let height = options.get("height", size)?.max(1);
+
+    Ok(Puzzle::new(grid_text(size, height, |_, _| {
+        rng.gen_range(b'1'..=b'9')
+    })))
+}
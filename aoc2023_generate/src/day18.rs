@@ -0,0 +1,123 @@
+use anyhow::Error;
+use rand::Rng as _;
+
+use crate::{Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 18,
+    params: &[
+        Param {
+            name: "size",
+            default: "180",
+            help: "columns the trench winds across; the plan has four moves per column",
+        },
+        Param {
+            name: "step",
+            default: "10",
+            help: "longest sideways move, and how far up or down a move goes, in part 1",
+        },
+        Param {
+            name: "color-step",
+            default: "500000",
+            help: "the same for the moves hidden in the colors for part 2",
+        },
+    ],
+    generate,
+};
+
+/// Both plans are loops around side-by-side columns of lagoon, each overlapping the next, so the
+/// trench never meets itself. The area inside them follows from the shoelace formula.
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 180usize)?.max(1);
+    // part 1 reads steps as u8 and part 2 has five hex digits, and a side can be twice the step
+    // long
+    let step = options.get("step", 10i64)?.clamp(2, 120);
+    let color_step = options.get("color-step", 500_000i64)?.clamp(2, 0x40000);
+
+    let (plan, part1) = column_loop(rng, size, step);
+    let (colors, part2) = column_loop(rng, size, color_step);
+
+    let mut input = String::new();
+    for ((direction, steps), (color_direction, color_steps)) in plan.iter().zip(colors.iter()) {
+        let digit = match color_direction {
+            'R' => 0,
+            'D' => 1,
+            'L' => 2,
+            _ => 3,
+        };
+        input.push_str(&format!(
+            "{} {} (#{:05x}{})\n",
+            direction, steps, color_steps, digit
+        ));
+    }
+    Ok(Puzzle {
+        input,
+        part1: Some(part1.to_string()),
+        part2: Some(part2.to_string()),
+    })
+}
+
+/// A clockwise loop around `columns` columns, as exactly four moves per column, and the lagoon
+/// it holds including the trench. Neighbouring columns never share a top or bottom, so no two
+/// moves in a row go the same way.
+fn column_loop(rng: &mut Rng, columns: usize, step: i64) -> (Vec<(char, i64)>, i64) {
+    let mut spans = Vec::<(i64, i64, i64)>::with_capacity(columns);
+    let top = rng.gen_range(0..step);
+    spans.push((rng.gen_range(1..=step), top, top + rng.gen_range(2..=step)));
+    while spans.len() < columns {
+        let (_, top, bottom) = spans[spans.len() - 1];
+        let mut next_top = rng.gen_range(bottom - step..bottom);
+        if next_top == top {
+            next_top = if top == bottom - 1 { top - 1 } else { top + 1 };
+        }
+        let low = top.max(next_top) + 1;
+        let mut next_bottom = rng.gen_range(low..low + step);
+        if next_bottom == bottom {
+            next_bottom += 1;
+        }
+        spans.push((rng.gen_range(1..=step), next_top, next_bottom));
+    }
+
+    let mut moves = Vec::with_capacity(columns * 4);
+    let vertical = |from: i64, to: i64| {
+        if to > from {
+            ('D', to - from)
+        } else {
+            ('U', from - to)
+        }
+    };
+    for (i, (width, top, _)) in spans.iter().enumerate() {
+        moves.push(('R', *width));
+        if let Some((_, next_top, _)) = spans.get(i + 1) {
+            moves.push(vertical(*top, *next_top));
+        }
+    }
+    let (_, last_top, last_bottom) = spans[columns - 1];
+    moves.push(vertical(last_top, last_bottom));
+    for (i, (width, _, bottom)) in spans.iter().enumerate().rev() {
+        moves.push(('L', *width));
+        if i > 0 {
+            moves.push(vertical(*bottom, spans[i - 1].2));
+        }
+    }
+    let (_, first_top, first_bottom) = spans[0];
+    moves.push(vertical(first_bottom, first_top));
+
+    // shoelace for the area inside the trench's centre line, then Pick's theorem for the
+    // blocks, inside and on the trench
+    let (mut x, mut y) = (0i64, first_top);
+    let mut twice_area = 0;
+    let mut length = 0;
+    for (direction, steps) in moves.iter() {
+        let (nx, ny) = match direction {
+            'R' => (x + steps, y),
+            'L' => (x - steps, y),
+            'D' => (x, y + steps),
+            _ => (x, y - steps),
+        };
+        twice_area += x * ny - nx * y;
+        length += steps;
+        (x, y) = (nx, ny);
+    }
+    (moves, twice_area.abs() / 2 + length / 2 + 1)
+}
@@ -0,0 +1,97 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use anyhow::Error;
+use rand::Rng as _;
+
+use crate::{Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 19,
+    params: &[
+        Param {
+            name: "size",
+            default: "550",
+            help: "number of workflows",
+        },
+        Param {
+            name: "rules",
+            default: "4",
+            help: "most rules in a workflow before its fallback",
+        },
+        Param {
+            name: "parts",
+            default: "200",
+            help: "number of parts",
+        },
+    ],
+    generate,
+};
+
+/// Workflows form a tree below `in`, so every part ends up accepted or rejected.
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let size = options.get("size", 550usize)?.max(1);
+    let rules = options.get("rules", 4usize)?.max(1);
+    let parts = options.get("parts", 200usize)?;
+
+    let mut names = BTreeSet::from(["in".to_owned()]);
+    let mut new_name = |rng: &mut Rng| loop {
+        let name = (0..rng.gen_range(2..=3))
+            .map(|_| rng.gen_range(b'a'..=b'z') as char)
+            .collect::<String>();
+        if names.insert(name.clone()) {
+            break name;
+        }
+    };
+
+    let mut workflows = Vec::with_capacity(size);
+    let mut pending = VecDeque::from(["in".to_owned()]);
+    let mut created = 1;
+    while let Some(name) = pending.pop_front() {
+        let steps = rng.gen_range(1..=rules) + 1;
+        // the first step always leads on while there are workflows left to make, so the tree
+        // doesn't stop growing early
+        let child = rng.gen_range(0..steps);
+        let mut targets = Vec::with_capacity(steps);
+        for step in 0..steps {
+            let target = if created < size && (step == child || rng.gen_bool(0.3)) {
+                created += 1;
+                let target = new_name(rng);
+                pending.push_back(target.clone());
+                target
+            } else if rng.gen_bool(0.5) {
+                "A".to_owned()
+            } else {
+                "R".to_owned()
+            };
+            targets.push(target);
+        }
+        let fallback = targets.pop().expect("every workflow has a fallback");
+        let body = targets
+            .into_iter()
+            .map(|target| {
+                format!(
+                    "{}{}{}:{}",
+                    b"xmas"[rng.gen_range(0..4)] as char,
+                    if rng.gen_bool(0.5) { '<' } else { '>' },
+                    rng.gen_range(1..=4000),
+                    target
+                )
+            })
+            .chain([fallback])
+            .collect::<Vec<_>>();
+        workflows.push(format!("{}{{{}}}", name, body.join(",")));
+    }
+
+    let mut input = workflows.join("\n");
+    input.push_str("\n\n");
+    for _ in 0..parts {
+        input.push_str(&format!(
+            "{{x={},m={},a={},s={}}}\n",
+            rng.gen_range(1..=4000),
+            rng.gen_range(1..=4000),
+            rng.gen_range(1..=4000),
+            rng.gen_range(1..=4000)
+        ));
+    }
+    Ok(Puzzle::new(input))
+}
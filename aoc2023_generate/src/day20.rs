@@ -0,0 +1,98 @@
+use anyhow::Error;
+use rand::seq::SliceRandom;
+
+use crate::{primes_in, Generator, Options, Param, Puzzle, Rng};
+
+pub const GENERATOR: Generator = Generator {
+    day: 20,
+    params: &[
+        Param {
+            name: "size",
+            default: "4",
+            help: "number of counters feeding rx",
+        },
+        Param {
+            name: "bits",
+            default: "12",
+            help: "flip-flops in each counter",
+        },
+    ],
+    generate,
+};
+
+/// Builds the same shape as the real puzzle: the broadcaster drives a handful of binary
+/// counters, each a chain of flip-flops watched by a conjunction that fires and resets the
+/// counter when it reaches a distinct prime. rx only gets a low pulse once every counter fires
+/// on the same press, so part 2 is the product of the primes.
+fn generate(rng: &mut Rng, options: &Options) -> Result<Puzzle, Error> {
+    let counters = options.get("size", 4usize)?.max(1);
+    let bits = options.get("bits", 12u32)?.clamp(3, 20);
+
+    let mut primes = primes_in(1 << (bits - 1)..1 << bits);
+    if primes.len() < counters {
+        return Err(Error::msg(format!(
+            "only {} primes with {} bits, need one per counter",
+            primes.len(),
+            bits
+        )));
+    }
+    primes.shuffle(rng);
+
+    let mut names = (b'a'..=b'z')
+        .flat_map(|a| (b'a'..=b'z').map(move |b| format!("{}{}", a as char, b as char)))
+        .filter(|name| name != "rx")
+        .collect::<Vec<_>>();
+    if names.len() < counters * (bits as usize + 2) + 1 {
+        return Err(Error::msg(format!(
+            "{} counters of {} bits need more than {} module names",
+            counters,
+            bits,
+            names.len()
+        )));
+    }
+    names.shuffle(rng);
+    let mut names = names.into_iter();
+    let mut name = || names.next().expect("enough names were checked for");
+
+    let hub = name();
+    let mut modules = vec![format!("&{} -> rx", hub)];
+    let mut starts = Vec::with_capacity(counters);
+    let mut part2 = 1u128;
+    for period in primes.into_iter().take(counters) {
+        part2 = part2
+            .checked_mul(u128::from(period))
+            .ok_or_else(|| Error::msg("the presses needed for rx don't fit in a u128"))?;
+        let flip_flops = (0..bits).map(|_| name()).collect::<Vec<_>>();
+        let watcher = name();
+        let inverter = name();
+        let mut resets = vec![flip_flops[0].clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs = Vec::new();
+            if let Some(next) = flip_flops.get(bit + 1) {
+                outputs.push(next.clone());
+            }
+            if period & (1 << bit) != 0 {
+                outputs.push(watcher.clone());
+            } else {
+                resets.push(flip_flop.clone());
+            }
+            outputs.shuffle(rng);
+            modules.push(format!("%{} -> {}", flip_flop, outputs.join(", ")));
+        }
+        resets.push(inverter.clone());
+        resets.shuffle(rng);
+        modules.push(format!("&{} -> {}", watcher, resets.join(", ")));
+        modules.push(format!("&{} -> {}", inverter, hub));
+        starts.push(flip_flops[0].clone());
+    }
+    modules.push(format!("broadcaster -> {}", starts.join(", ")));
+    modules.shuffle(rng);
+
+    let mut input = modules.join("\n");
+    input.push('\n');
+    Ok(Puzzle {
+        input,
+        part1: None,
+        part2: Some(part2.to_string()),
+    })
+}
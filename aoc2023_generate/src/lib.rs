@@ -0,0 +1,187 @@
+//! Random puzzle inputs for stress and scaling tests.
+//!
+//! Each day has a module that builds a valid input from a seed and a handful of named
+//! parameters controlling its size and shape. Every day takes a `size`; the other parameters are
+//! listed in its [`Generator::params`]. Days whose structure makes it easy also return the
+//! answers the input was built to have.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use anyhow::{Context, Error};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+
+/// The random number generator handed to every day, seeded so the same options always build the
+/// same input.
+pub type Rng = ChaCha8Rng;
+
+/// A generated puzzle input, with the answers it was built to have where they are known.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Puzzle {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Puzzle {
+    /// A puzzle whose answers aren't known up front.
+    pub fn new(input: String) -> Self {
+        Self {
+            input,
+            part1: None,
+            part2: None,
+        }
+    }
+}
+
+/// A parameter a day's generator understands.
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub help: &'static str,
+}
+
+/// Builds inputs for one day.
+pub struct Generator {
+    pub day: u8,
+    pub params: &'static [Param],
+    generate: fn(&mut Rng, &Options) -> Result<Puzzle, Error>,
+}
+
+impl Generator {
+    pub fn generate(&self, seed: u64, options: &Options) -> Result<Puzzle, Error> {
+        for name in options.params.keys() {
+            if !self.params.iter().any(|param| param.name == *name) {
+                let known = self.params.iter().map(|param| param.name);
+                return Err(Error::msg(format!(
+                    "day {} has no parameter {:?}, only {}",
+                    self.day,
+                    name,
+                    known.collect::<Vec<_>>().join(", ")
+                )));
+            }
+        }
+        let mut rng = Rng::seed_from_u64(seed);
+        (self.generate)(&mut rng, options).with_context(|| format!("day {}", self.day))
+    }
+}
+
+/// Parameter values set by the caller; anything left out takes the day's default.
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    params: BTreeMap<String, String>,
+}
+
+impl Options {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets `name` to `value`.
+    pub fn set<N: Into<String>, V: ToString>(mut self, name: N, value: V) -> Self {
+        self.params.insert(name.into(), value.to_string());
+        self
+    }
+
+    /// Sets a parameter from `name=value`.
+    pub fn set_pair(self, pair: &str) -> Result<Self, Error> {
+        let (name, value) = pair
+            .split_once('=')
+            .with_context(|| format!("expected NAME=VALUE, got {:?}", pair))?;
+        Ok(self.set(name, value))
+    }
+
+    /// The value of `name`, or `default` if it wasn't set.
+    pub fn get<T>(&self, name: &str, default: T) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        match self.params.get(name) {
+            Some(value) => value
+                .parse()
+                .with_context(|| format!("invalid {} {:?}", name, value)),
+            None => Ok(default),
+        }
+    }
+}
+
+pub static GENERATORS: &[Generator] = &[
+    day01::GENERATOR,
+    day02::GENERATOR,
+    day03::GENERATOR,
+    day04::GENERATOR,
+    day05::GENERATOR,
+    day06::GENERATOR,
+    day07::GENERATOR,
+    day08::GENERATOR,
+    day09::GENERATOR,
+    day10::GENERATOR,
+    day11::GENERATOR,
+    day12::GENERATOR,
+    day13::GENERATOR,
+    day14::GENERATOR,
+    day15::GENERATOR,
+    day16::GENERATOR,
+    day17::GENERATOR,
+    day18::GENERATOR,
+    day19::GENERATOR,
+    day20::GENERATOR,
+];
+
+pub fn find(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+/// A grid of cells as puzzle text, one row per line.
+fn grid_text(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> u8) -> String {
+    let mut text = String::with_capacity((width + 1) * height);
+    for y in 0..height {
+        for x in 0..width {
+            text.push(cell(x, y) as char);
+        }
+        text.push('\n');
+    }
+    text
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a / gcd(a, b) * b
+}
+
+/// Primes in `range`, smallest first.
+fn primes_in(range: std::ops::Range<u64>) -> Vec<u64> {
+    range
+        .filter(|n| *n >= 2 && (2..).take_while(|d| d * d <= *n).all(|d| n % d != 0))
+        .collect()
+}
@@ -0,0 +1,166 @@
+use aoc2023_common::Part;
+use aoc2023_generate::{find, Options, GENERATORS};
+
+type Params = &'static [(&'static str, &'static str)];
+
+/// Small options for every day that keep the slower solvers quick.
+const DAYS: &[(u8, Params)] = &[
+    (1, &[("size", "50")]),
+    (2, &[("size", "20")]),
+    (3, &[("size", "20")]),
+    (4, &[("size", "20")]),
+    (5, &[("size", "8")]),
+    (6, &[]),
+    (7, &[("size", "50")]),
+    (8, &[("size", "20")]),
+    (9, &[("size", "20")]),
+    (10, &[("size", "30")]),
+    (11, &[("size", "20")]),
+    (12, &[("size", "20")]),
+    (13, &[("size", "10")]),
+    (14, &[("size", "20")]),
+    (15, &[("size", "100")]),
+    (16, &[("size", "20")]),
+    (17, &[("size", "20")]),
+    (18, &[("size", "20")]),
+    (19, &[("size", "30")]),
+    (20, &[("size", "2"), ("bits", "4")]),
+];
+
+/// Solves `part` of `day` for `input`.
+fn solve(day: u8, part: Part, input: &str) -> Result<String, anyhow::Error> {
+    let entry = aoc2023_days::find(day, Some(part)).next().unwrap();
+    Ok(entry.run(input)?.answer)
+}
+
+fn options(params: &[(&str, &str)]) -> Options {
+    params
+        .iter()
+        .fold(Options::new(), |options, (name, value)| {
+            options.set(*name, value)
+        })
+}
+
+#[test]
+fn every_day_has_a_generator() {
+    assert_eq!(GENERATORS.len() * 2, aoc2023_days::ENTRIES.len());
+    for entry in aoc2023_days::ENTRIES {
+        assert!(find(entry.day).is_some(), "day {}", entry.day);
+        assert!(
+            DAYS.iter().any(|(day, _)| *day == entry.day),
+            "day {}",
+            entry.day
+        );
+    }
+}
+
+#[test]
+fn generated_inputs_solve_to_their_known_answers() {
+    for (day, params) in DAYS {
+        for seed in 0..5 {
+            let puzzle = find(*day)
+                .unwrap()
+                .generate(seed, &options(params))
+                .unwrap();
+            for (part, known) in [(Part::One, &puzzle.part1), (Part::Two, &puzzle.part2)] {
+                let answer = solve(*day, part, &puzzle.input)
+                    .unwrap_or_else(|e| panic!("day {} seed {}: {:#}", day, seed, e));
+                if let Some(known) = known {
+                    assert_eq!(&answer, known, "day {} seed {}", day, seed);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn days_with_known_structure_know_their_answers() {
+    for day in [8, 9, 10, 13, 18] {
+        let puzzle = find(day).unwrap().generate(1, &Options::new()).unwrap();
+        for (part, known) in [(Part::One, puzzle.part1), (Part::Two, puzzle.part2)] {
+            let known = known.unwrap_or_else(|| panic!("day {} part {}", day, part));
+            let answer = solve(day, part, &puzzle.input)
+                .unwrap_or_else(|e| panic!("day {} part {}: {:#}", day, part, e));
+            assert_eq!(answer, known, "day {} part {}", day, part);
+        }
+    }
+    // day 20's default part 2 takes far too many presses to check by solving it
+    let puzzle = find(20).unwrap().generate(1, &Options::new()).unwrap();
+    assert!(puzzle.part2.is_some());
+}
+
+#[test]
+fn same_seed_same_input() {
+    for generator in GENERATORS {
+        let options = Options::new().set("size", 10);
+        let first = generator.generate(7, &options).unwrap();
+        assert_eq!(first, generator.generate(7, &options).unwrap());
+        assert_ne!(first.input, generator.generate(8, &options).unwrap().input);
+    }
+}
+
+#[test]
+fn size_scales_the_input() {
+    for generator in GENERATORS.iter().filter(|generator| generator.day != 20) {
+        let small = generator
+            .generate(0, &Options::new().set("size", 5))
+            .unwrap();
+        let large = generator
+            .generate(0, &Options::new().set("size", 50))
+            .unwrap();
+        assert!(
+            large.input.len() > small.input.len(),
+            "day {}",
+            generator.day
+        );
+    }
+}
+
+#[test]
+fn unknown_parameters_are_rejected() {
+    let error = find(8)
+        .unwrap()
+        .generate(0, &Options::new().set("width", 3))
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "day 8 has no parameter \"width\", only size, ghosts, cycle"
+    );
+    assert!(Options::new().set_pair("size").is_err());
+    assert!(find(8)
+        .unwrap()
+        .generate(0, &Options::new().set("size", "big"))
+        .is_err());
+}
+
+#[test]
+fn large_inputs_fit_on_the_stack() {
+    // test threads have a small stack, which the recursive beam and flood fill used to overflow
+    let puzzle = find(16)
+        .unwrap()
+        .generate(0, &Options::new().set("size", 2000))
+        .unwrap();
+    solve(16, Part::One, &puzzle.input).unwrap();
+
+    let puzzle = find(18)
+        .unwrap()
+        .generate(0, &Options::new().set("size", 2000))
+        .unwrap();
+    assert_eq!(
+        Some(solve(18, Part::One, &puzzle.input).unwrap()),
+        puzzle.part1
+    );
+}
+
+#[test]
+fn day08_rejects_too_many_nodes() {
+    let error = find(8)
+        .unwrap()
+        .generate(0, &Options::new().set("size", 263))
+        .unwrap_err();
+    assert!(
+        format!("{:#}", error).contains("three-letter names"),
+        "{:#}",
+        error
+    );
+}
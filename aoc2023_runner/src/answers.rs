@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+use std::io;
+use std::path::Path;
+
+use anyhow::{Context, Error};
+use aoc2023_common::Part;
+use toml::Value;
+
+/// Known-correct answers, stored as one TOML table per day keyed by part number:
+///
+/// ```toml
+/// [1]
+/// 1 = 54331
+/// 2 = "54518"
+/// ```
+///
+/// The answers for a directory of inputs, as `aoc batch` checks them, are keyed by input file
+/// name instead of by day.
+#[derive(Debug)]
+pub struct Answers<K = u8> {
+    answers: BTreeMap<(K, Part), String>,
+}
+
+impl<K> Default for Answers<K> {
+    fn default() -> Self {
+        Self {
+            answers: BTreeMap::new(),
+        }
+    }
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let text = read_to_string(path).with_context(|| path.display().to_string())?;
+        Self::parse(&text).with_context(|| path.display().to_string())
+    }
+
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        parse_tables(text, |day_key| {
+            let day = day_key
+                .parse::<u8>()
+                .with_context(|| format!("invalid day {:?}", day_key))?;
+            Ok((day, format!("day {}", day)))
+        })
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+}
+
+impl Answers<String> {
+    /// Loads answers keyed by input file name, or none if `path` doesn't exist.
+    pub fn load_inputs(path: &Path) -> Result<Self, Error> {
+        match read_to_string(path) {
+            Ok(text) => Self::parse_inputs(&text).with_context(|| path.display().to_string()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::new(e).context(path.display().to_string())),
+        }
+    }
+
+    pub fn parse_inputs(text: &str) -> Result<Self, Error> {
+        parse_tables(text, |name| Ok((name.to_owned(), name.to_owned())))
+    }
+
+    pub fn get_input(&self, name: &str, part: Part) -> Option<&str> {
+        self.answers
+            .get(&(name.to_owned(), part))
+            .map(String::as_str)
+    }
+}
+
+/// Parses tables of answers keyed by part number, using `key_of` to turn each table's name into a
+/// key and a description for errors.
+fn parse_tables<K, F>(text: &str, key_of: F) -> Result<Answers<K>, Error>
+where
+    K: Clone + Ord,
+    F: Fn(&str) -> Result<(K, String), Error>,
+{
+    let tables = toml::from_str::<BTreeMap<String, BTreeMap<String, Value>>>(text)?;
+
+    let mut answers = BTreeMap::new();
+    for (table, parts) in tables {
+        let (key, what) = key_of(&table)?;
+        for (part_key, value) in parts {
+            let part = part_key
+                .parse::<u8>()
+                .ok()
+                .and_then(Part::from_number)
+                .with_context(|| format!("{}: invalid part {:?}", what, part_key))?;
+            let answer = match value {
+                Value::String(s) => s,
+                Value::Integer(i) => i.to_string(),
+                _ => {
+                    return Err(Error::msg(format!(
+                        "{} part {}: answer must be a string or integer",
+                        what, part
+                    )))
+                }
+            };
+            answers.insert((key.clone(), part), answer);
+        }
+    }
+
+    Ok(Answers { answers })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_strings_and_integers() {
+        let answers =
+            Answers::parse("[1]\n1 = 142\n2 = \"281\"\n\n[19]\n2 = 167409079868000\n").unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("142"));
+        assert_eq!(answers.get(1, Part::Two), Some("281"));
+        assert_eq!(answers.get(19, Part::Two), Some("167409079868000"));
+        assert_eq!(answers.get(19, Part::One), None);
+        assert_eq!(answers.get(2, Part::One), None);
+    }
+
+    #[test]
+    fn parse_rejects_bad_keys() {
+        assert!(Answers::parse("[one]\n1 = 142\n").is_err());
+        assert!(Answers::parse("[1]\n3 = 142\n").is_err());
+        assert!(Answers::parse("[1]\n1 = 1.5\n").is_err());
+    }
+
+    #[test]
+    fn parse_inputs_by_file_name() {
+        let answers = Answers::parse_inputs("[\"alice.txt\"]\n1 = 6\n2 = \"7\"\n").unwrap();
+        assert_eq!(answers.get_input("alice.txt", Part::One), Some("6"));
+        assert_eq!(answers.get_input("alice.txt", Part::Two), Some("7"));
+        assert_eq!(answers.get_input("bob.txt", Part::One), None);
+        assert_eq!(
+            Answers::parse_inputs("[\"bob.txt\"]\n3 = 6\n")
+                .unwrap_err()
+                .to_string(),
+            "bob.txt: invalid part \"3\""
+        );
+    }
+}
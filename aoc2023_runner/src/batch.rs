@@ -0,0 +1,221 @@
+use std::fs::{read_dir, read_to_string};
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Context, Error};
+use aoc2023_common::{with_params, Part, Report};
+
+use crate::answers::Answers;
+use crate::config::{check_overrides, Config};
+use crate::parallel::{parse_options, run_tasks, Outcome, Task};
+use crate::{check_failed, parse_day, parse_part, USAGE};
+
+/// How many times slower than the median of the other inputs an input has to be to stand out.
+const SLOW_FACTOR: f64 = 100.0;
+
+/// Inputs quicker than this are never flagged as slow, however they compare; at these times
+/// the differences are mostly noise.
+const SLOW_FLOOR: Duration = Duration::from_millis(10);
+
+/// `aoc batch <day> <dir> [part] [--jobs N] [--timeout SECS] [--param NAME=VALUE]...`
+///
+/// Runs the day on every input in `dir`, such as those of several accounts, and tabulates the
+/// answers and timings per input. Answers that disagree with `dir/answers.toml`, which holds a
+/// table per input file keyed by part like `answers.toml`, count as failures. Inputs that take
+/// orders of magnitude longer than the rest are flagged, since that usually means the solver
+/// leans on something only some inputs have.
+pub fn batch(args: &[&str]) -> Result<(), Error> {
+    let (day, dir, part, args) = match args {
+        [day, dir, part, rest @ ..] if !part.starts_with('-') => (
+            parse_day(day)?,
+            Path::new(dir),
+            Some(parse_part(part)?),
+            rest,
+        ),
+        [day, dir, rest @ ..] => (parse_day(day)?, Path::new(dir), None, rest),
+        _ => return Err(Error::msg(USAGE)),
+    };
+    let options = parse_options(args)?;
+    let entries = aoc2023_days::find(day, part).collect::<Vec<_>>();
+    check_overrides(entries.iter().copied(), &options.params)?;
+    let config = Config::load(Path::new("aoc.toml"))?;
+    let answers = Answers::load_inputs(&dir.join("answers.toml"))?;
+
+    let inputs = input_files(dir)?;
+    if inputs.is_empty() {
+        return Err(Error::msg(format!("no inputs in {}", dir.display())));
+    }
+    let mut tasks = Vec::new();
+    let mut runs = Vec::new();
+    for entry in entries.iter().copied() {
+        for (name, input) in inputs.iter() {
+            let params = config.params(entry, &options.params);
+            let input = input.clone();
+            tasks.push(Task {
+                day: entry.day,
+                part: entry.part,
+                run: Box::new(move || with_params(params, || entry.report(&input))),
+            });
+            runs.push((entry.part, name.as_str()));
+        }
+    }
+    let outcomes = run_tasks(tasks, &options);
+
+    let slow = slow_outliers(
+        &runs
+            .iter()
+            .zip(outcomes.iter())
+            .map(|((part, _), (outcome, elapsed))| match outcome {
+                Outcome::Finished(Report {
+                    answer: Some(_), ..
+                }) => (*part, Some(*elapsed)),
+                _ => (*part, None),
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    let width = inputs
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or_default()
+        .max("input".len());
+    println!(
+        "{:<width$}  {:>4}  {:<7}  {:>12}  answer",
+        "input",
+        "part",
+        "status",
+        "elapsed",
+        width = width
+    );
+    let (mut failed, mut flagged) = (0, 0);
+    for (((part, name), (outcome, elapsed)), slow) in
+        runs.iter().zip(outcomes.iter()).zip(slow.iter())
+    {
+        let mut notes = Vec::new();
+        let (status, answer) = match outcome {
+            Outcome::Finished(Report {
+                answer: Some(answer),
+                ..
+            }) => match answers.get_input(name, *part) {
+                Some(expected) if expected != answer => {
+                    notes.push(format!("expected {}", expected));
+                    ("wrong", answer.as_str())
+                }
+                _ => ("ok", answer.as_str()),
+            },
+            Outcome::Finished(Report {
+                interrupted: true,
+                error: Some(error),
+                ..
+            }) => ("timeout", error.as_str()),
+            Outcome::Finished(report) => {
+                let error = report.error.as_deref().unwrap_or_default();
+                ("error", error.lines().next().unwrap_or_default())
+            }
+            Outcome::TimedOut => ("timeout", "-"),
+        };
+        if status != "ok" {
+            failed += 1;
+        }
+        if let Some(factor) = slow {
+            notes.push(format!("SLOW: {:.0}x the median of the others", factor));
+            flagged += 1;
+        }
+        let notes = if notes.is_empty() {
+            String::new()
+        } else {
+            format!("  ({})", notes.join(", "))
+        };
+        println!(
+            "{:<width$}  {:>4}  {:<7}  {:>12}  {}{}",
+            name,
+            part,
+            status,
+            format!("{:.2?}", elapsed),
+            answer,
+            notes,
+            width = width
+        );
+    }
+    if flagged != 0 {
+        eprintln!("{} input(s) much slower than the rest", flagged);
+    }
+
+    check_failed(failed)
+}
+
+/// Every file in `dir` by name, in name order, apart from `answers.toml` and the checksums the
+/// input cache keeps.
+fn input_files(dir: &Path) -> Result<Vec<(String, String)>, Error> {
+    let mut inputs = Vec::new();
+    for file in read_dir(dir).with_context(|| dir.display().to_string())? {
+        let path = file?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if !path.is_file()
+            || name == "answers.toml"
+            || name.ends_with(".sha256")
+            || name.starts_with('.')
+        {
+            continue;
+        }
+        let input = read_to_string(&path).with_context(|| path.display().to_string())?;
+        inputs.push((name.to_owned(), input));
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// For each run that finished, how many times slower it was than the median of the other runs
+/// of the same part, if that is at least [`SLOW_FACTOR`].
+fn slow_outliers(runs: &[(Part, Option<Duration>)]) -> Vec<Option<f64>> {
+    runs.iter()
+        .enumerate()
+        .map(|(i, (part, elapsed))| {
+            let elapsed = (*elapsed).filter(|elapsed| *elapsed >= SLOW_FLOOR)?;
+            let mut others = runs
+                .iter()
+                .enumerate()
+                .filter(|(j, (other, _))| *j != i && other == part)
+                .filter_map(|(_, (_, elapsed))| *elapsed)
+                .collect::<Vec<_>>();
+            if others.is_empty() {
+                return None;
+            }
+            others.sort();
+            let median = others[others.len() / 2].as_secs_f64().max(f64::EPSILON);
+            let factor = elapsed.as_secs_f64() / median;
+            (factor >= SLOW_FACTOR).then_some(factor)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_inputs_orders_of_magnitude_slower() {
+        let ms = |ms| Some(Duration::from_millis(ms));
+        let runs = [
+            (Part::One, ms(1)),
+            (Part::One, ms(2)),
+            (Part::One, ms(500)),
+            (Part::One, None),
+            (Part::Two, ms(3)),
+            (Part::Two, ms(9)),
+            (Part::Two, Some(Duration::from_micros(5))),
+            (Part::Two, Some(Duration::from_micros(4))),
+        ];
+        let slow = slow_outliers(&runs);
+        assert_eq!(slow[..2], [None, None]);
+        assert_eq!(slow[2].map(f64::round), Some(250.0));
+        assert_eq!(slow[3..5], [None, None]);
+        // far slower than the median, but under the floor
+        assert_eq!(slow[5..], [None, None, None]);
+
+        assert_eq!(slow_outliers(&[(Part::One, ms(500))]), [None]);
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, write};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Error};
+use aoc2023_common::{parse_timeout, with_budget, Budget};
+use aoc2023_days::Entry;
+use serde::{Deserialize, Serialize};
+
+use crate::{check_failed, input_path, parse_day, read_input, DEFAULT_TIMEOUT, USAGE};
+
+/// A part this many times slower than its baseline is reported as a regression.
+const REGRESSION_FACTOR: f64 = 2.0;
+
+/// Parts quicker than this are never reported as regressions, however they compare; a few
+/// microseconds either way is mostly noise.
+const REGRESSION_FLOOR: Duration = Duration::from_millis(1);
+
+struct Options {
+    baseline: PathBuf,
+    day: Option<u8>,
+    runs: usize,
+    save: bool,
+    /// How long each run of a part may take.
+    timeout: Duration,
+}
+
+/// Median parse and solve times of one part, in nanoseconds.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+struct Timing {
+    parse: u64,
+    solve: u64,
+}
+
+impl Timing {
+    fn total(&self) -> u64 {
+        self.parse + self.solve
+    }
+}
+
+/// Saved timings, keyed by day and then part like `answers.toml`.
+type Baseline = BTreeMap<String, BTreeMap<String, Timing>>;
+
+/// Median and range of one phase over all runs.
+struct Stats {
+    max: Duration,
+    median: Duration,
+    min: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        Self {
+            max: samples[samples.len() - 1],
+            median,
+            min: samples[0],
+        }
+    }
+}
+
+/// `aoc bench <day|all> [--runs N] [--baseline FILE] [--save] [--timeout SECS]`
+pub fn bench(args: &[&str]) -> Result<(), Error> {
+    let options = parse_options(args)?;
+
+    let baseline = load_baseline(&options.baseline)?;
+    let mut results = Baseline::new();
+    let mut failed = 0;
+    let mut regressed = 0;
+
+    println!(
+        "{:>3} {:>4}  {:>30}  {:>30}  baseline",
+        "day", "part", "parse median (min..max)", "solve median (min..max)"
+    );
+
+    let entries = match options.day {
+        Some(day) => aoc2023_days::find(day, None).collect::<Vec<_>>(),
+        None => aoc2023_days::ENTRIES.iter().collect(),
+    };
+    for entry in entries {
+        let Some(input) = read_input(Path::new("inputs"), entry.day)? else {
+            eprintln!(
+                "day {} part {}: no input at {}",
+                entry.day,
+                entry.part,
+                input_path(Path::new("inputs"), entry.day).display()
+            );
+            continue;
+        };
+
+        let (parse, solve) = match time_entry(entry, &input, options.runs, options.timeout) {
+            Ok(stats) => stats,
+            Err(e) => {
+                eprintln!("{:#}", e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let timing = Timing {
+            parse: parse.median.as_nanos() as u64,
+            solve: solve.median.as_nanos() as u64,
+        };
+        let comparison = match baseline
+            .get(&entry.day.to_string())
+            .and_then(|parts| parts.get(&entry.part.to_string()))
+        {
+            Some(old) => {
+                let ratio = timing.total() as f64 / old.total().max(1) as f64;
+                if is_regression(&timing, old) {
+                    regressed += 1;
+                    format!("{:.2}x SLOWER", ratio)
+                } else {
+                    format!("{:.2}x", ratio)
+                }
+            }
+            None => "-".to_owned(),
+        };
+
+        println!(
+            "{:>3} {:>4}  {:>30}  {:>30}  {}",
+            entry.day,
+            entry.part,
+            format_stats(&parse),
+            format_stats(&solve),
+            comparison
+        );
+
+        results
+            .entry(entry.day.to_string())
+            .or_default()
+            .insert(entry.part.to_string(), timing);
+    }
+
+    if options.save {
+        save_baseline(&options.baseline, baseline, results)?;
+    }
+
+    check_failed(failed)?;
+    if regressed != 0 {
+        return Err(Error::msg(format!(
+            "{} part(s) at least {}x slower than the baseline",
+            regressed, REGRESSION_FACTOR
+        )));
+    }
+    Ok(())
+}
+
+/// Whether `timing` is at least [`REGRESSION_FACTOR`] times `old`, and slow enough for that to
+/// mean something.
+fn is_regression(timing: &Timing, old: &Timing) -> bool {
+    let ratio = timing.total() as f64 / old.total().max(1) as f64;
+    ratio >= REGRESSION_FACTOR && timing.total() >= REGRESSION_FLOOR.as_nanos() as u64
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "{:.2?} ({:.2?}..{:.2?})",
+        stats.median, stats.min, stats.max
+    )
+}
+
+fn load_baseline(path: &Path) -> Result<Baseline, Error> {
+    match read_to_string(path) {
+        Ok(text) => toml::from_str(&text).with_context(|| path.display().to_string()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Baseline::new()),
+        Err(e) => Err(Error::new(e).context(path.display().to_string())),
+    }
+}
+
+fn parse_options(mut args: &[&str]) -> Result<Options, Error> {
+    let mut options = Options {
+        baseline: PathBuf::from("bench.toml"),
+        day: None,
+        runs: 10,
+        save: false,
+        timeout: DEFAULT_TIMEOUT,
+    };
+    let mut selected = false;
+
+    loop {
+        match args {
+            [] => break,
+            ["--runs", runs, rest @ ..] => {
+                options.runs = runs.parse().context("invalid number of runs")?;
+                if options.runs == 0 {
+                    return Err(Error::msg("need at least one run"));
+                }
+                args = rest;
+            }
+            ["--baseline", path, rest @ ..] => {
+                options.baseline = PathBuf::from(path);
+                args = rest;
+            }
+            ["--save", rest @ ..] => {
+                options.save = true;
+                args = rest;
+            }
+            ["--timeout", seconds, rest @ ..] => {
+                options.timeout = parse_timeout(seconds)?;
+                args = rest;
+            }
+            ["all", rest @ ..] if !selected => {
+                selected = true;
+                args = rest;
+            }
+            [day, rest @ ..] if !selected && !day.starts_with('-') => {
+                options.day = Some(parse_day(day)?);
+                selected = true;
+                args = rest;
+            }
+            _ => return Err(Error::msg(USAGE)),
+        }
+    }
+
+    if !selected {
+        return Err(Error::msg(USAGE));
+    }
+    Ok(options)
+}
+
+/// Merges `results` over `baseline`, so benchmarking a single day keeps the other days' numbers.
+fn save_baseline(path: &Path, mut baseline: Baseline, results: Baseline) -> Result<(), Error> {
+    for (day, parts) in results {
+        baseline.entry(day).or_default().extend(parts);
+    }
+    write(path, toml::to_string(&baseline)?).with_context(|| path.display().to_string())?;
+    eprintln!("saved baseline to {}", path.display());
+    Ok(())
+}
+
+/// Times `runs` runs of one part, stopping at the first that fails or runs out of `timeout`.
+fn time_entry(
+    entry: &Entry,
+    input: &str,
+    runs: usize,
+    timeout: Duration,
+) -> Result<(Stats, Stats), Error> {
+    let mut parse_times = Vec::with_capacity(runs);
+    let mut solve_times = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let run = with_budget(Budget::timeout(timeout), || entry.run(input))
+            .with_context(|| format!("day {} part {}", entry.day, entry.part))?;
+        parse_times.push(run.parse_time);
+        solve_times.push(run.solve_time);
+    }
+    Ok((Stats::new(parse_times), Stats::new(solve_times)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    #[test]
+    fn stats_odd_samples() {
+        let stats = Stats::new(millis(&[9, 1, 5]));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.max, Duration::from_millis(9));
+    }
+
+    #[test]
+    fn stats_even_samples() {
+        let stats = Stats::new(millis(&[4, 1, 2, 8]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn regressions_need_the_floor() {
+        let timing = |nanos| Timing {
+            parse: 0,
+            solve: nanos,
+        };
+        assert!(!is_regression(&timing(7_000), &timing(3_000)));
+        assert!(is_regression(&timing(7_000_000), &timing(3_000_000)));
+        assert!(!is_regression(&timing(5_000_000), &timing(3_000_000)));
+    }
+
+    #[test]
+    fn options_select_day_and_flags() {
+        let options = parse_options(&["--runs", "3", "all", "--save"]).unwrap();
+        assert_eq!(options.day, None);
+        assert_eq!(options.runs, 3);
+        assert!(options.save);
+
+        assert_eq!(options.timeout, DEFAULT_TIMEOUT);
+
+        let options = parse_options(&["1", "--baseline", "old.toml", "--timeout", "5"]).unwrap();
+        assert_eq!(options.day, Some(1));
+        assert_eq!(options.baseline, PathBuf::from("old.toml"));
+        assert_eq!(options.timeout, Duration::from_secs(5));
+
+        assert!(parse_options(&[]).is_err());
+        assert!(parse_options(&["1", "2"]).is_err());
+        assert!(parse_options(&["all", "--runs", "0"]).is_err());
+    }
+}
@@ -0,0 +1,213 @@
+use std::time::Duration;
+
+use anyhow::{Context, Error};
+use aoc2023_common::Part;
+use ureq::{Agent, AgentBuilder};
+
+use crate::config::{Site, SESSION_ENV};
+
+/// The puzzle year every request is made for.
+const YEAR: u16 = 2023;
+
+/// Talks to the puzzle site, or anything serving the same paths, as a logged-in user.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    /// The whole `Cookie` header sent with every request.
+    cookie: String,
+}
+
+impl Client {
+    pub fn new(site: &Site) -> Result<Self, Error> {
+        let session = site.session.as_deref().with_context(|| {
+            format!(
+                "no session token; set session under [site] in aoc.toml or ${}",
+                SESSION_ENV
+            )
+        })?;
+        let agent = AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(concat!("aoc2023_runner/", env!("CARGO_PKG_VERSION")))
+            .build();
+        Ok(Self {
+            agent,
+            base_url: site.base_url.trim_end_matches('/').to_owned(),
+            cookie: format!("session={}", session),
+        })
+    }
+
+    /// Downloads the puzzle input for `day`.
+    pub fn fetch_input(&self, day: u8) -> Result<String, Error> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &self.cookie)
+            .call()
+            .map_err(|e| request_error(e, day))?;
+        response
+            .into_string()
+            .with_context(|| format!("reading {}", url))
+    }
+
+    /// Submits `answer` for `part` of `day`, returning the verdict and the page it came in.
+    pub fn submit_answer(
+        &self,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<(Verdict, String), Error> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, YEAR, day);
+        let level = part.to_string();
+        let page = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie)
+            .send_form(&[("level", &level), ("answer", answer)])
+            .map_err(|e| request_error(e, day))?
+            .into_string()
+            .with_context(|| format!("reading {}", url))?;
+        let verdict = Verdict::parse(&page).with_context(|| format!("submitting to {}", url))?;
+        Ok((verdict, page))
+    }
+}
+
+/// What the site made of a submitted answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Wrong,
+    /// Not checked because the last answer was too recent; try again after this long.
+    TooSoon(Duration),
+    /// Not checked because the part is already solved or not unlocked yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the site answers a submission with.
+    pub fn parse(page: &str) -> Result<Self, Error> {
+        if page.contains("That's the right answer") {
+            Ok(Self::Correct)
+        } else if page.contains("That's not the right answer") {
+            Ok(if page.contains("your answer is too high") {
+                Self::TooHigh
+            } else if page.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Wrong
+            })
+        } else if page.contains("You gave an answer too recently") {
+            Ok(Self::TooSoon(
+                parse_wait(page).unwrap_or(Duration::from_secs(60)),
+            ))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Ok(Self::WrongLevel)
+        } else {
+            Err(Error::msg("couldn't find a verdict in the response"))
+        }
+    }
+
+    /// How long the site makes you wait before the next submission.
+    pub fn wait(&self, page: &str) -> Duration {
+        match self {
+            Self::Correct | Self::WrongLevel => Duration::ZERO,
+            Self::TooSoon(wait) => *wait,
+            // the site asks for a minute at first and more after repeated misses
+            Self::TooHigh | Self::TooLow | Self::Wrong => {
+                parse_wait(page).unwrap_or(Duration::from_secs(60))
+            }
+        }
+    }
+}
+
+/// Finds a wait like "You have 1m 5s left to wait" or "please wait 5 minutes" in `page`.
+fn parse_wait(page: &str) -> Option<Duration> {
+    if let Some((_, rest)) = page.split_once("You have ") {
+        let (time, _) = rest.split_once(" left to wait")?;
+        let mut seconds = 0;
+        for piece in time.split_whitespace() {
+            seconds += match piece.split_at(piece.len().checked_sub(1)?) {
+                (number, "m") => number.parse::<u64>().ok()? * 60,
+                (number, "s") => number.parse::<u64>().ok()?,
+                _ => return None,
+            };
+        }
+        return Some(Duration::from_secs(seconds));
+    }
+    let (_, rest) = page.split_once("wait ")?;
+    let (amount, rest) = rest.split_once(' ')?;
+    let minutes = match amount {
+        "one" => 1,
+        amount => amount.parse::<u64>().ok()?,
+    };
+    rest.starts_with("minute")
+        .then(|| Duration::from_secs(minutes * 60))
+}
+
+/// Turns a failed request into an error saying what probably went wrong.
+fn request_error(error: ureq::Error, day: u8) -> Error {
+    match error {
+        ureq::Error::Status(404, _) => {
+            Error::msg(format!("day {} isn't available yet (HTTP 404)", day))
+        }
+        ureq::Error::Status(code @ (400 | 401 | 403), _) => Error::msg(format!(
+            "the session token was rejected (HTTP {}); log in again and copy a fresh one",
+            code
+        )),
+        ureq::Error::Status(code, response) => {
+            let body = response.into_string().unwrap_or_default();
+            Error::msg(format!(
+                "HTTP {}: {}",
+                code,
+                body.lines().next().unwrap_or_default()
+            ))
+        }
+        ureq::Error::Transport(e) => Error::new(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn verdicts() {
+        let correct = page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.");
+        assert_eq!(Verdict::parse(&correct).unwrap(), Verdict::Correct);
+        assert_eq!(Verdict::Correct.wait(&correct), Duration::ZERO);
+
+        let high = page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [<a href=\"/2023/day/5\">Return to Day 5</a>]");
+        assert_eq!(Verdict::parse(&high).unwrap(), Verdict::TooHigh);
+        assert_eq!(Verdict::TooHigh.wait(&high), Duration::from_secs(60));
+
+        let low = page("That's not the right answer; your answer is too low.  Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again.");
+        assert_eq!(Verdict::parse(&low).unwrap(), Verdict::TooLow);
+        assert_eq!(Verdict::TooLow.wait(&low), Duration::from_secs(300));
+
+        let wrong = page("That's not the right answer.  If you're stuck, make sure you're using the full input data.");
+        assert_eq!(Verdict::parse(&wrong).unwrap(), Verdict::Wrong);
+        assert_eq!(Verdict::Wrong.wait(&wrong), Duration::from_secs(60));
+
+        let soon = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.");
+        assert_eq!(
+            Verdict::parse(&soon).unwrap(),
+            Verdict::TooSoon(Duration::from_secs(65))
+        );
+
+        let level =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(Verdict::parse(&level).unwrap(), Verdict::WrongLevel);
+
+        assert!(Verdict::parse("<html>Puzzle inputs differ by user.</html>").is_err());
+    }
+}
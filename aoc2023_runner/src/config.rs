@@ -0,0 +1,162 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs::read_to_string;
+use std::io;
+use std::path::Path;
+
+use anyhow::{Context, Error};
+use aoc2023_common::Params;
+use aoc2023_days::Entry;
+use serde::Deserialize;
+
+/// Environment variable that overrides the session token in `aoc.toml`.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Settings read from `aoc.toml`, all of them optional:
+///
+/// ```toml
+/// [site]
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+///
+/// # puzzle parameters for `run` and `run-all`, keyed by day and then part
+/// [params.11.2]
+/// expansion = 10
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub site: Site,
+    params: BTreeMap<String, BTreeMap<String, BTreeMap<String, toml::Value>>>,
+}
+
+/// Where puzzle inputs come from and who to fetch them as.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Site {
+    /// The `session` cookie of a logged-in browser.
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Default for Site {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: "https://adventofcode.com".to_owned(),
+        }
+    }
+}
+
+impl Config {
+    /// Loads `path`, or the defaults if it doesn't exist, then applies [`SESSION_ENV`].
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let mut config = match read_to_string(path) {
+            Ok(text) => Self::parse(&text).with_context(|| path.display().to_string())?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(Error::new(e).context(path.display().to_string())),
+        };
+        if let Ok(session) = env::var(SESSION_ENV) {
+            config.site.session = Some(session);
+        }
+        Ok(config)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        Ok(toml::from_str(text)?)
+    }
+
+    /// The parameters to run `entry` with: those set for it here, overridden by any in
+    /// `overrides` that it takes.
+    pub fn params(&self, entry: &Entry, overrides: &Params) -> Params {
+        let mut params = Params::default();
+        let configured = self
+            .params
+            .get(&entry.day.to_string())
+            .and_then(|parts| parts.get(&entry.part.to_string()));
+        for (name, value) in configured.into_iter().flatten() {
+            match value {
+                toml::Value::String(value) => params.set(name, value),
+                value => params.set(name, value.to_string()),
+            }
+        }
+        for name in overrides.names() {
+            if entry.params.iter().any(|param| param.name == name) {
+                params.set(name, overrides.get(name).unwrap_or_default());
+            }
+        }
+        params
+    }
+}
+
+/// Fails if a parameter in `overrides` isn't taken by any of `entries`, which would otherwise
+/// be silently ignored.
+pub fn check_overrides<'a, I>(entries: I, overrides: &Params) -> Result<(), Error>
+where
+    I: IntoIterator<Item = &'a Entry>,
+{
+    let entries = entries.into_iter().collect::<Vec<_>>();
+    for name in overrides.names() {
+        let taken = entries
+            .iter()
+            .any(|entry| entry.params.iter().any(|param| param.name == name));
+        if !taken {
+            return Err(Error::msg(format!(
+                "no part to run takes parameter {:?}",
+                name
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023_common::Part;
+
+    use super::*;
+
+    #[test]
+    fn parse_site() {
+        let config =
+            Config::parse("[site]\nsession = \"abc\"\nbase_url = \"http://localhost:8080\"\n")
+                .unwrap();
+        assert_eq!(config.site.session.as_deref(), Some("abc"));
+        assert_eq!(config.site.base_url, "http://localhost:8080");
+    }
+
+    #[test]
+    fn parse_defaults() {
+        let config = Config::parse("").unwrap();
+        assert_eq!(config.site.session, None);
+        assert_eq!(config.site.base_url, "https://adventofcode.com");
+        assert!(Config::parse("[site]\ntoken = \"abc\"\n").is_err());
+    }
+
+    #[test]
+    fn params_from_file_and_overrides() {
+        let config = Config::parse(
+            "[params.17.2]\nmin_run = 2\nmax_run = 3\n[params.20.2]\ntarget = \"zz\"\n",
+        )
+        .unwrap();
+        let day17 = aoc2023_days::find(17, Some(Part::Two)).next().unwrap();
+        let day20 = aoc2023_days::find(20, None).collect::<Vec<_>>();
+
+        let mut overrides = Params::default();
+        overrides.set("max_run", "5");
+        let params = config.params(day17, &overrides);
+        assert_eq!(params.get("min_run"), Some("2"));
+        assert_eq!(params.get("max_run"), Some("5"));
+        assert_eq!(config.params(day20[0], &overrides), Params::default());
+        assert_eq!(
+            config.params(day20[1], &overrides).get("target"),
+            Some("zz")
+        );
+
+        assert!(check_overrides([day17], &overrides).is_ok());
+        assert_eq!(
+            check_overrides(day20, &overrides).unwrap_err().to_string(),
+            "no part to run takes parameter \"max_run\""
+        );
+    }
+}
@@ -0,0 +1,388 @@
+use std::fs::{create_dir_all, read_dir, read_to_string, remove_file, write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Error};
+use aoc2023_common::{catch_panic, with_budget, Budget};
+use aoc2023_days::Entry;
+
+use crate::{parse_day, USAGE};
+
+/// How long a candidate example gets to solve; real examples take microseconds.
+const EXAMPLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// `aoc examples <day> <page.html> [--dry-run]`
+///
+/// Reads a puzzle page saved from the site and turns its examples into each part's test
+/// fixtures: `tests/examples/example*.txt` and the matching tests in `tests/examples.rs`. Any
+/// other tests in that file are kept.
+///
+/// A page shows far more in `<pre><code>` blocks than example inputs, so each block is run
+/// through the solver and kept only if its answer is one of those the part's description
+/// emphasizes. Part 2 may use the blocks from part 1's description too, since it usually reuses
+/// them.
+pub fn examples(args: &[&str]) -> Result<(), Error> {
+    let (day, page, dry_run) = match args {
+        [day, page] => (parse_day(day)?, page, false),
+        [day, page, "--dry-run"] => (parse_day(day)?, page, true),
+        _ => return Err(Error::msg(USAGE)),
+    };
+    let page = read_to_string(page).with_context(|| page.to_string())?;
+    let articles = articles(&page);
+    if articles.is_empty() {
+        return Err(Error::msg("no puzzle description on the page"));
+    }
+
+    for entry in aoc2023_days::find(day, None) {
+        let index = entry.part.number() as usize - 1;
+        let Some(article) = articles.get(index) else {
+            println!(
+                "day {} part {}: not on the page; save it after solving part 1",
+                day, entry.part
+            );
+            continue;
+        };
+        let examples = match_examples(entry, &articles[..=index], &article.answers);
+        if examples.is_empty() {
+            println!(
+                "day {} part {}: none of {} block(s) solve to one of the answers {:?}",
+                day,
+                entry.part,
+                articles[..=index]
+                    .iter()
+                    .map(|article| article.blocks.len())
+                    .sum::<usize>(),
+                article.answers
+            );
+            continue;
+        }
+
+        let names = example_names(examples.len());
+        for (name, (_, answer)) in names.iter().zip(examples.iter()) {
+            println!("day {} part {}: {}.txt = {}", day, entry.part, name, answer);
+        }
+        if !dry_run {
+            write_fixtures(
+                &Path::new(&entry.crate_name()).join("tests"),
+                entry,
+                &examples,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// The description of one part: its `<pre><code>` blocks and the `<code><em>` answers it
+/// emphasizes, as plain text.
+#[derive(Debug, Default, PartialEq)]
+struct Article {
+    blocks: Vec<String>,
+    answers: Vec<String>,
+}
+
+/// The `<article>`s of a puzzle page, part 1 first.
+fn articles(page: &str) -> Vec<Article> {
+    elements(page, "<article", "</article>")
+        .map(|article| Article {
+            blocks: elements(article, "<pre><code>", "</code></pre>")
+                .map(text)
+                .collect(),
+            answers: elements(article, "<code><em>", "</em></code>")
+                .map(text)
+                .collect(),
+        })
+        .collect()
+}
+
+/// What is between each `open` and the `close` after it. Puzzle pages never nest these.
+fn elements<'a>(
+    html: &'a str,
+    open: &'a str,
+    close: &'a str,
+) -> impl Iterator<Item = &'a str> + 'a {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        let start = rest.find(open)?;
+        let after = &rest[start + open.len()..];
+        let end = after.find(close)?;
+        rest = &after[end + close.len()..];
+        Some(&after[..end])
+    })
+}
+
+/// `html` with its tags dropped and its entities decoded.
+fn text(html: &str) -> String {
+    let mut stripped = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+
+    let mut decoded = String::with_capacity(stripped.len());
+    let mut rest = stripped.as_str();
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| Some((decode_entity(&rest[1..end])?, end)));
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        _ => {
+            let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// The distinct blocks of `articles` that `entry` solves to one of `answers`, with the answer.
+fn match_examples(
+    entry: &Entry,
+    articles: &[Article],
+    answers: &[String],
+) -> Vec<(String, String)> {
+    let mut examples = Vec::<(String, String)>::new();
+    for block in articles.iter().flat_map(|article| article.blocks.iter()) {
+        if examples.iter().any(|(example, _)| example == block) {
+            continue;
+        }
+        // most candidate blocks aren't examples, and the solver may well panic on them
+        let report =
+            catch_panic(|| with_budget(Budget::timeout(EXAMPLE_TIMEOUT), || entry.report(block)));
+        let Ok(report) = report else {
+            continue;
+        };
+        if let Some(answer) = report.answer.filter(|answer| answers.contains(answer)) {
+            examples.push((block.clone(), answer));
+        }
+    }
+
+    examples
+}
+
+/// `example` for a lone example, otherwise `example1`, `example2` and so on.
+fn example_names(count: usize) -> Vec<String> {
+    if count == 1 {
+        return vec!["example".to_owned()];
+    }
+    (1..=count).map(|i| format!("example{}", i)).collect()
+}
+
+/// Replaces the example fixtures under `tests` with `examples`.
+fn write_fixtures(tests: &Path, entry: &Entry, examples: &[(String, String)]) -> Result<(), Error> {
+    let dir = tests.join("examples");
+    create_dir_all(&dir).with_context(|| dir.display().to_string())?;
+    for stale in read_dir(&dir).with_context(|| dir.display().to_string())? {
+        let path = stale?.path();
+        if is_example_name(path.file_stem().and_then(|stem| stem.to_str()))
+            && path.extension().is_some_and(|extension| extension == "txt")
+        {
+            remove_file(&path).with_context(|| path.display().to_string())?;
+        }
+    }
+
+    let names = example_names(examples.len());
+    let mut tests_rs = Vec::new();
+    for (name, (input, answer)) in names.iter().zip(examples.iter()) {
+        let path = dir.join(format!("{}.txt", name));
+        write(&path, input).with_context(|| path.display().to_string())?;
+        tests_rs.push(format!(
+            "#[test]\nfn {name}() {{\n    let answer = {krate}::solve(include_str!(\"examples/{name}.txt\")).unwrap();\n    assert_eq!(answer, {answer:?});\n}}\n",
+            name = name,
+            krate = entry.crate_name(),
+            answer = answer,
+        ));
+    }
+
+    let path: PathBuf = tests.join("examples.rs");
+    let existing = match read_to_string(&path) {
+        Ok(existing) => existing,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(Error::new(e).context(path.display().to_string())),
+    };
+    write(&path, replace_example_tests(&existing, &tests_rs))
+        .with_context(|| path.display().to_string())
+}
+
+fn is_example_name(name: Option<&str>) -> bool {
+    name.and_then(|name| name.strip_prefix("example"))
+        .is_some_and(|number| number.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// `source` with its `example*` tests swapped for `tests`, which go where the first of them was
+/// or else after the imports. Every other test is left as it was.
+fn replace_example_tests(source: &str, tests: &[String]) -> String {
+    let lines = source.lines().collect::<Vec<_>>();
+    let mut kept = Vec::new();
+    let mut insert_at = None;
+    let mut i = 0;
+    while i < lines.len() {
+        let is_example = lines[i] == "#[test]"
+            && lines.get(i + 1).is_some_and(|line| {
+                is_example_name(
+                    line.strip_prefix("fn ")
+                        .and_then(|line| line.strip_suffix("() {")),
+                )
+            });
+        if !is_example {
+            kept.push(lines[i]);
+            i += 1;
+            continue;
+        }
+        insert_at.get_or_insert(kept.len());
+        i = (i..lines.len())
+            .find(|j| lines[*j] == "}")
+            .map_or(lines.len(), |end| end + 1);
+        if lines.get(i).is_some_and(|line| line.is_empty()) {
+            i += 1;
+        }
+    }
+    let insert_at = insert_at.unwrap_or_else(|| {
+        kept.iter()
+            .position(|line| !line.is_empty() && !line.starts_with("use "))
+            .unwrap_or(kept.len())
+    });
+
+    let mut rewritten = String::new();
+    for line in &kept[..insert_at] {
+        rewritten.push_str(line);
+        rewritten.push('\n');
+    }
+    rewritten.push_str(&tests.join("\n"));
+    if insert_at < kept.len() {
+        rewritten.push('\n');
+    }
+    for line in &kept[insert_at..] {
+        rewritten.push_str(line);
+        rewritten.push('\n');
+    }
+    rewritten
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::remove_dir_all;
+    use std::process;
+
+    use aoc2023_common::Part;
+
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the calibration values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>, and <code>77</code>. Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Equipped with this new information, you now need to find the real first and last digit on each line. For example:</p>
+<pre><code>two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+</code></pre>
+<p>In this example, the calibration values are <code>29</code>, <code>83</code>, <code>13</code>, <code>24</code>, <code>42</code>, <code>14</code>, and <code>76</code>. Adding these together produces <code><em>281</em></code>.</p>
+<p>Not an input: <code>&lt;a&gt; &amp;&#x41;</code></p><pre><code>&lt;a&gt; &amp;&#x41;<em>!</em></code></pre>
+</article>
+</main>"#;
+
+    fn entry(part: Part) -> &'static Entry {
+        aoc2023_days::find(1, Some(part)).next().unwrap()
+    }
+
+    #[test]
+    fn finds_blocks_that_solve_to_emphasized_answers() {
+        let articles = articles(PAGE);
+        assert_eq!(articles.len(), 2);
+        assert_eq!(articles[0].answers, ["142"]);
+        assert_eq!(articles[1].blocks[1], "<a> &A!");
+
+        let examples = match_examples(entry(Part::One), &articles[..1], &articles[0].answers);
+        assert_eq!(examples.len(), 1);
+        assert!(examples[0].0.starts_with("1abc2\n"));
+        assert_eq!(examples[0].1, "142");
+
+        // part 1's example solves to 142 under part 2's rules too, which isn't an answer there
+        let examples = match_examples(entry(Part::Two), &articles, &articles[1].answers);
+        assert_eq!(examples.len(), 1);
+        assert!(examples[0].0.starts_with("two1nine\n"));
+        assert_eq!(examples[0].1, "281");
+    }
+
+    #[test]
+    fn rewrites_only_example_tests() {
+        let source = "use aoc2023_common::InputError;\n\n#[test]\nfn example1() {\n    let answer = 1;\n}\n\n#[test]\nfn example2() {\n}\n\n#[test]\nfn malformed_input() {\n    let error = 2;\n\n    drop(error);\n}\n";
+        let tests = ["#[test]\nfn example() {\n}\n".to_owned()];
+        assert_eq!(
+            replace_example_tests(source, &tests),
+            "use aoc2023_common::InputError;\n\n#[test]\nfn example() {\n}\n\n#[test]\nfn malformed_input() {\n    let error = 2;\n\n    drop(error);\n}\n"
+        );
+
+        let source = "use aoc2023_common::InputError;\n\n#[test]\nfn malformed_input() {\n}\n";
+        assert_eq!(
+            replace_example_tests(source, &tests),
+            "use aoc2023_common::InputError;\n\n#[test]\nfn example() {\n}\n\n#[test]\nfn malformed_input() {\n}\n"
+        );
+        assert_eq!(replace_example_tests("", &tests), tests[0]);
+    }
+
+    #[test]
+    fn writes_fixtures() {
+        let tests = temp_dir().join(format!("aoc-examples-{}", process::id()));
+        let _ = remove_dir_all(&tests);
+        create_dir_all(tests.join("examples")).unwrap();
+        write(tests.join("examples/example1.txt"), "stale").unwrap();
+        write(tests.join("examples/counter.txt"), "kept").unwrap();
+
+        let examples = [("1abc2\n".to_owned(), "12".to_owned())];
+        write_fixtures(&tests, entry(Part::One), &examples).unwrap();
+        assert_eq!(
+            read_to_string(tests.join("examples/example.txt")).unwrap(),
+            "1abc2\n"
+        );
+        assert!(!tests.join("examples/example1.txt").exists());
+        assert!(tests.join("examples/counter.txt").exists());
+        assert_eq!(
+            read_to_string(tests.join("examples.rs")).unwrap(),
+            "#[test]\nfn example() {\n    let answer = aoc2023_01::solve(include_str!(\"examples/example.txt\")).unwrap();\n    assert_eq!(answer, \"12\");\n}\n"
+        );
+        remove_dir_all(&tests).unwrap();
+    }
+}
@@ -0,0 +1,52 @@
+use std::io::{self, Write};
+
+use anyhow::{Context, Error};
+use aoc2023_generate::Options;
+
+use crate::USAGE;
+
+/// `aoc generate <day> [--seed N] [NAME=VALUE]...`
+///
+/// Writes the input to stdout, and whichever answers it was built to have to stderr, so the two
+/// can be redirected separately.
+pub fn generate(args: &[&str]) -> Result<(), Error> {
+    let [day, args @ ..] = args else {
+        return Err(Error::msg(USAGE));
+    };
+    let mut args = args;
+    let day = day.parse::<u8>().context("invalid day")?;
+    let generator =
+        aoc2023_generate::find(day).with_context(|| format!("no generator for day {}", day))?;
+
+    let mut seed = 0;
+    let mut options = Options::new();
+    loop {
+        match args {
+            [] => break,
+            ["--seed", value, rest @ ..] => {
+                seed = value.parse().context("invalid seed")?;
+                args = rest;
+            }
+            ["--params", ..] => {
+                for param in generator.params {
+                    println!("{:<12} {:>8}  {}", param.name, param.default, param.help);
+                }
+                return Ok(());
+            }
+            [pair, rest @ ..] if !pair.starts_with('-') => {
+                options = options.set_pair(pair)?;
+                args = rest;
+            }
+            _ => return Err(Error::msg(USAGE)),
+        }
+    }
+
+    let puzzle = generator.generate(seed, &options)?;
+    io::stdout().write_all(puzzle.input.as_bytes())?;
+    for (part, answer) in [(1, &puzzle.part1), (2, &puzzle.part2)] {
+        if let Some(answer) = answer {
+            eprintln!("day {} part {}: {}", day, part, answer);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,244 @@
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::{self, Write as _};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Error};
+use sha2::{Digest, Sha256};
+
+use crate::client::Client;
+use crate::config::Config;
+use crate::{input_path, parse_day, USAGE};
+
+/// Puzzle inputs saved on disk as `NN.txt`. A downloaded one is saved next to a `NN.txt.sha256`
+/// holding its checksum, so an input that was edited or truncated since is noticed rather than
+/// silently used. Every command that reads a saved input goes through [`Cache::get`].
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The cached input for `day`, or `None` if it hasn't been saved. An input saved by hand,
+    /// without a checksum, is trusted as it is.
+    pub fn get(&self, day: u8) -> Result<Option<String>, Error> {
+        let path = input_path(&self.dir, day);
+        let input = match read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(Error::new(e).context(path.display().to_string())),
+        };
+
+        let checksum_path = checksum_path(&path);
+        match read_to_string(&checksum_path) {
+            Ok(expected) if expected.trim() == checksum(&input) => Ok(Some(input)),
+            Ok(_) => Err(Error::msg(format!(
+                "{} doesn't match its checksum; delete it to fetch it again",
+                path.display()
+            ))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Some(input)),
+            Err(e) => Err(Error::new(e).context(checksum_path.display().to_string())),
+        }
+    }
+
+    /// Saves a downloaded input for `day` along with its checksum.
+    pub fn put(&self, day: u8, input: &str) -> Result<(), Error> {
+        create_dir_all(&self.dir).with_context(|| self.dir.display().to_string())?;
+        let path = input_path(&self.dir, day);
+        write(&path, input).with_context(|| path.display().to_string())?;
+        let checksum_path = checksum_path(&path);
+        write(&checksum_path, checksum(input) + "\n")
+            .with_context(|| checksum_path.display().to_string())
+    }
+
+    /// The input for `day` from the cache, downloading and caching it first if it isn't there.
+    /// A cached day is never fetched again.
+    pub fn get_or_fetch(&self, day: u8, config: &Config) -> Result<String, Error> {
+        if let Some(input) = self.get(day)? {
+            return Ok(input);
+        }
+        let input = Client::new(&config.site)?.fetch_input(day)?;
+        self.put(day, &input)?;
+        eprintln!(
+            "saved day {} input to {}",
+            day,
+            input_path(&self.dir, day).display()
+        );
+        Ok(input)
+    }
+}
+
+fn checksum_path(path: &Path) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".sha256");
+    PathBuf::from(path)
+}
+
+/// SHA-256 of `input` as lowercase hex.
+fn checksum(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// `aoc input <day>`: prints the day's input, fetching it into the cache if needed.
+pub fn input(args: &[&str]) -> Result<(), Error> {
+    let [day] = args else {
+        return Err(Error::msg(USAGE));
+    };
+    let day = parse_day(day)?;
+    let config = Config::load(Path::new("aoc.toml"))?;
+    let input = Cache::new("inputs").get_or_fetch(day, &config)?;
+    io::stdout().write_all(input.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::remove_dir_all;
+    use std::process;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    use tiny_http::{Response, Server};
+
+    use super::*;
+    use crate::read_input;
+
+    /// A stand-in for the puzzle site serving `input` to anyone with session `token`, counting
+    /// the requests it gets.
+    fn serve(input: &'static str) -> (String, Arc<AtomicUsize>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let authorized = request.headers().iter().any(|header| {
+                    header.field.equiv("Cookie") && header.value.as_str() == "session=token"
+                });
+                let response = match (request.url(), authorized) {
+                    (_, false) => Response::from_string("log in").with_status_code(400),
+                    ("/2023/day/5/input", true) => Response::from_string(input),
+                    _ => Response::from_string("not found").with_status_code(404),
+                };
+                let _ = request.respond(response);
+            }
+        });
+        (base_url, requests)
+    }
+
+    fn config(base_url: &str, session: Option<&str>) -> Config {
+        let mut config = Config::default();
+        config.site.base_url = base_url.to_owned();
+        config.site.session = session.map(str::to_owned);
+        config
+    }
+
+    /// An empty directory to cache into, removed when dropped.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = temp_dir().join(format!("aoc-input-{}-{}", process::id(), name));
+            let _ = remove_dir_all(&dir);
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn fetches_once_then_uses_cache() {
+        let (base_url, requests) = serve("seeds: 79 14 55 13\n");
+        let dir = TempDir::new("cache");
+        let cache = Cache::new(&dir.0);
+        let config = config(&base_url, Some("token"));
+
+        assert_eq!(cache.get(5).unwrap(), None);
+        assert_eq!(
+            cache.get_or_fetch(5, &config).unwrap(),
+            "seeds: 79 14 55 13\n"
+        );
+        assert_eq!(
+            cache.get_or_fetch(5, &config).unwrap(),
+            "seeds: 79 14 55 13\n"
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            read_to_string(dir.0.join("05.txt.sha256")).unwrap(),
+            checksum("seeds: 79 14 55 13\n") + "\n"
+        );
+    }
+
+    #[test]
+    fn edited_input_fails_its_checksum() {
+        let dir = TempDir::new("checksum");
+        let cache = Cache::new(&dir.0);
+        cache.put(5, "seeds: 79 14 55 13\n").unwrap();
+        write(dir.0.join("05.txt"), "seeds: 79 14\n").unwrap();
+
+        let error = cache.get(5).unwrap_err().to_string();
+        assert!(error.ends_with("doesn't match its checksum; delete it to fetch it again"));
+    }
+
+    #[test]
+    fn hand_saved_input_is_used_as_is() {
+        let dir = TempDir::new("adopt");
+        create_dir_all(&dir.0).unwrap();
+        write(dir.0.join("05.txt"), "seeds: 79\n").unwrap();
+
+        let cache = Cache::new(&dir.0);
+        assert_eq!(cache.get(5).unwrap().as_deref(), Some("seeds: 79\n"));
+        assert!(!dir.0.join("05.txt.sha256").exists());
+    }
+
+    #[test]
+    fn every_read_checks_the_checksum() {
+        let dir = TempDir::new("read");
+        Cache::new(&dir.0).put(5, "seeds: 79 14 55 13\n").unwrap();
+        assert_eq!(
+            read_input(&dir.0, 5).unwrap().as_deref(),
+            Some("seeds: 79 14 55 13\n")
+        );
+
+        write(dir.0.join("05.txt"), "seeds: 79 14\n").unwrap();
+        assert!(read_input(&dir.0, 5).is_err());
+        assert_eq!(read_input(&dir.0, 6).unwrap(), None);
+    }
+
+    #[test]
+    fn fetch_errors_explain_themselves() {
+        let (base_url, requests) = serve("");
+        let dir = TempDir::new("errors");
+        let cache = Cache::new(&dir.0);
+
+        let error = cache
+            .get_or_fetch(6, &config(&base_url, Some("token")))
+            .unwrap_err();
+        assert_eq!(error.to_string(), "day 6 isn't available yet (HTTP 404)");
+
+        let error = cache
+            .get_or_fetch(5, &config(&base_url, Some("stale")))
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("the session token was rejected"));
+
+        let error = cache.get_or_fetch(5, &config(&base_url, None)).unwrap_err();
+        assert!(error.to_string().starts_with("no session token"));
+
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        assert_eq!(cache.get(5).unwrap(), None);
+    }
+}
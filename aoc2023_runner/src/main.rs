@@ -0,0 +1,282 @@
+mod answers;
+mod batch;
+mod bench;
+mod client;
+mod config;
+mod examples;
+mod generate;
+mod input;
+mod parallel;
+mod serve;
+mod submit;
+mod verify;
+
+use std::env::args;
+use std::io::{self, stdin, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Error};
+use aoc2023_common::{
+    init_logging_from, parse_timeout, with_budget, with_params, Budget, Params, Part,
+};
+use aoc2023_days::{Entry, ENTRIES};
+
+use config::{check_overrides, Config};
+
+const USAGE: &str = "usage: aoc [--log FILTER] run <day|all> [part] [--json|--cross-check] [--timeout SECS] [--param NAME=VALUE]...
+       aoc [--log FILTER] run-all [--jobs N] [--timeout SECS] [--param NAME=VALUE]...
+       aoc [--log FILTER] batch <day> <dir> [part] [--jobs N] [--timeout SECS] [--param NAME=VALUE]...
+       aoc [--log FILTER] verify [answers.toml] [--timeout SECS]
+       aoc input <day>
+       aoc submit <day> <part> [answer] [--timeout SECS]
+       aoc [--log FILTER] bench <day|all> [--runs N] [--baseline FILE] [--save] [--timeout SECS]
+       aoc generate <day> [--seed N] [NAME=VALUE]...
+       aoc generate <day> --params
+       aoc examples <day> <page.html> [--dry-run]
+       aoc serve [--addr HOST:PORT] [--workers N] [--max-input BYTES] [--timeout SECS]
+
+FILTER is a list like `info,10_2=trace`; it defaults to $AOC_LOG, then `info`.
+`run <day>` and `submit` read the input from stdin, or from the `inputs` cache when stdin is a terminal.
+`run all`, `run-all`, `batch`, `verify`, `submit`, `bench` and `serve` give each part 60 seconds unless told otherwise.
+`run` and `run-all` take puzzle parameters from `aoc.toml`, then from `--param` for each part that has them.
+`run --cross-check` solves each part with every strategy it has, and fails if any two disagree.
+`serve` answers `POST /days/<day>/parts/<part>` with the input as the body, on 127.0.0.1:2023 by default.";
+
+/// How long a part may run when no `--timeout` is given, for the commands that have a default.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+fn main() -> Result<(), Error> {
+    let args = args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let args = match args.as_slice() {
+        ["--log", filter, rest @ ..] => {
+            init_logging_from(Some(filter))?;
+            rest
+        }
+        rest => {
+            init_logging_from(None)?;
+            rest
+        }
+    };
+
+    match args {
+        ["run", rest @ ..] => run(rest),
+        ["run-all", rest @ ..] => parallel::run_all(rest),
+        ["batch", rest @ ..] => batch::batch(rest),
+        ["verify", rest @ ..] => verify::verify(rest),
+        ["bench", rest @ ..] => bench::bench(rest),
+        ["input", rest @ ..] => input::input(rest),
+        ["submit", rest @ ..] => submit::submit(rest),
+        ["generate", rest @ ..] => generate::generate(rest),
+        ["examples", rest @ ..] => examples::examples(rest),
+        ["serve", rest @ ..] => serve::serve(rest),
+        _ => Err(Error::msg(USAGE)),
+    }
+}
+
+fn check_failed(failed: usize) -> Result<(), Error> {
+    if failed != 0 {
+        return Err(Error::msg(format!("{} part(s) failed", failed)));
+    }
+    Ok(())
+}
+
+fn parse_day(text: &str) -> Result<u8, Error> {
+    let day = text.parse::<u8>().context("invalid day")?;
+    if !ENTRIES.iter().any(|entry| entry.day == day) {
+        return Err(Error::msg(format!("day {} not solved", day)));
+    }
+    Ok(day)
+}
+
+fn parse_part(text: &str) -> Result<Part, Error> {
+    text.parse::<u8>()
+        .ok()
+        .and_then(Part::from_number)
+        .context("part must be 1 or 2")
+}
+
+/// How `aoc run` prints each part.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Output {
+    /// The answer and timings.
+    Text,
+    /// The [`Report`] as a line of JSON.
+    ///
+    /// [`Report`]: aoc2023_common::Report
+    Json,
+    /// The answer and solve time of each strategy.
+    CrossCheck,
+}
+
+/// Runs one part and prints its answer as `output` says.
+fn print_run(entry: &Entry, input: &str, output: Output) -> Result<(), Error> {
+    if output == Output::Json {
+        let report = entry.report(input);
+        println!("{}", report.to_json());
+        return match report.error {
+            Some(error) => Err(Error::msg(format!(
+                "day {} part {}: {}",
+                entry.day, entry.part, error
+            ))),
+            None => Ok(()),
+        };
+    }
+
+    if output == Output::CrossCheck {
+        let runs = entry
+            .cross_check(input)
+            .with_context(|| format!("day {} part {}", entry.day, entry.part))?;
+        println!("day {} part {}: {}", entry.day, entry.part, runs[0].answer);
+        for run in runs {
+            println!("  {}: solve {:?}", run.name, run.solve_time);
+        }
+        return Ok(());
+    }
+
+    let run = entry
+        .run(input)
+        .with_context(|| format!("day {} part {}", entry.day, entry.part))?;
+    println!(
+        "day {} part {}: {} (parse {:?}, solve {:?})",
+        entry.day, entry.part, run.answer, run.parse_time, run.solve_time
+    );
+    Ok(())
+}
+
+/// Reads the input for `day` from the `inputs` directory, or `None` if it hasn't been saved.
+/// Fails if it no longer matches the checksum it was downloaded with.
+fn read_input(inputs: &Path, day: u8) -> Result<Option<String>, Error> {
+    input::Cache::new(inputs).get(day)
+}
+
+fn input_path(inputs: &Path, day: u8) -> PathBuf {
+    inputs.join(format!("{:02}.txt", day))
+}
+
+/// `aoc run <day|all> [part] [--json|--cross-check] [--timeout SECS] [--param NAME=VALUE]...`
+fn run(mut args: &[&str]) -> Result<(), Error> {
+    let mut output = Output::Text;
+    let mut timeout = None;
+    let mut overrides = Params::default();
+    loop {
+        match args {
+            [rest @ .., flag @ ("--json" | "--cross-check")] => {
+                let flag_output = match *flag {
+                    "--json" => Output::Json,
+                    _ => Output::CrossCheck,
+                };
+                if output != Output::Text && output != flag_output {
+                    return Err(Error::msg(
+                        "--json and --cross-check can't be used together",
+                    ));
+                }
+                output = flag_output;
+                args = rest;
+            }
+            [rest @ .., "--timeout", seconds] => {
+                timeout = Some(parse_timeout(seconds)?);
+                args = rest;
+            }
+            [rest @ .., "--param", assignment] => {
+                overrides.set_assignment(assignment)?;
+                args = rest;
+            }
+            _ => break,
+        }
+    }
+    let (day, part) = match args {
+        ["all"] => (None, None),
+        [day] => (Some(parse_day(day)?), None),
+        [day, part] => (Some(parse_day(day)?), Some(parse_part(part)?)),
+        _ => return Err(Error::msg(USAGE)),
+    };
+
+    // a single day may take as long as it needs, but `run all` shouldn't hang on one part
+    if day.is_none() {
+        timeout.get_or_insert(DEFAULT_TIMEOUT);
+    }
+
+    let config = Config::load(Path::new("aoc.toml"))?;
+    let settings = Settings {
+        output,
+        budget: &|| timeout.map_or_else(Budget::unlimited, Budget::timeout),
+        params: &|entry| config.params(entry, &overrides),
+    };
+    match day {
+        None => {
+            check_overrides(ENTRIES, &overrides)?;
+            run_all(Path::new("inputs"), &settings)
+        }
+        Some(day) => {
+            check_overrides(aoc2023_days::find(day, part), &overrides)?;
+            run_day(day, part, &settings)
+        }
+    }
+}
+
+/// How `aoc run` runs each part.
+struct Settings<'a> {
+    output: Output,
+    /// A fresh budget for each part.
+    budget: &'a dyn Fn() -> Budget,
+    params: &'a dyn Fn(&Entry) -> Params,
+}
+
+impl Settings<'_> {
+    fn print_run(&self, entry: &Entry, input: &str) -> Result<(), Error> {
+        with_budget((self.budget)(), || {
+            with_params((self.params)(entry), || {
+                print_run(entry, input, self.output)
+            })
+        })
+    }
+}
+
+/// Runs every part in turn.
+fn run_all(inputs: &Path, settings: &Settings) -> Result<(), Error> {
+    let mut failed = 0;
+    for entry in ENTRIES.iter() {
+        let Some(input) = read_input(inputs, entry.day)? else {
+            eprintln!(
+                "day {} part {}: no input at {}",
+                entry.day,
+                entry.part,
+                input_path(inputs, entry.day).display()
+            );
+            continue;
+        };
+        if let Err(e) = settings.print_run(entry, &input) {
+            eprintln!("{:#}", e);
+            failed += 1;
+        }
+    }
+    check_failed(failed)
+}
+
+/// Reads the input for `day` from stdin, or from the `inputs` cache when stdin is a terminal.
+fn read_day_input(day: u8) -> Result<String, Error> {
+    if !stdin().is_terminal() {
+        return Ok(io::read_to_string(stdin())?);
+    }
+    read_input(Path::new("inputs"), day)?.with_context(|| {
+        format!(
+            "no cached input for day {}; fetch it with `aoc input {}` or pipe it in",
+            day, day
+        )
+    })
+}
+
+fn run_day(day: u8, part: Option<Part>, settings: &Settings) -> Result<(), Error> {
+    let input = read_day_input(day)?;
+    let mut failed = 0;
+    for entry in aoc2023_days::find(day, part) {
+        if let Err(e) = settings.print_run(entry, &input) {
+            eprintln!("{:#}", e);
+            failed += 1;
+        }
+    }
+    check_failed(failed)
+}
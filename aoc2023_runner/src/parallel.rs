@@ -0,0 +1,439 @@
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Error};
+use aoc2023_common::{
+    catch_panic, parse_timeout, spawn_solver, with_budget, with_params, Budget, Params, Part,
+    Report,
+};
+use aoc2023_days::ENTRIES;
+
+use crate::config::{check_overrides, Config};
+use crate::{check_failed, input_path, read_input, DEFAULT_TIMEOUT, USAGE};
+
+/// How long past its timeout a part that doesn't check its budget gets before it is abandoned.
+pub const GRACE: Duration = Duration::from_secs(1);
+
+pub struct Options {
+    pub jobs: usize,
+    pub timeout: Duration,
+    /// Parameters from `--param`, overriding `aoc.toml`'s.
+    pub params: Params,
+}
+
+/// A part to run on a worker thread, producing its report.
+pub struct Task {
+    pub day: u8,
+    pub part: Part,
+    pub run: Box<dyn FnOnce() -> Report + Send>,
+}
+
+/// How a task ended.
+pub enum Outcome {
+    Finished(Report),
+    /// Still running well after its time ran out; the thread is left behind.
+    TimedOut,
+}
+
+enum Message {
+    /// A worker took a task; setting the flag tells the worker to stop once the task ends,
+    /// because it has been replaced.
+    Started(usize, Budget, Arc<AtomicBool>),
+    Finished(usize, Report, Duration),
+}
+
+/// `aoc run-all [--jobs N] [--timeout SECS] [--param NAME=VALUE]...`
+///
+/// Runs every part at once and prints a table of the results. Each part's log output is captured
+/// on its own thread and printed after the table, along with any errors too long for it, rather
+/// than interleaved with the others.
+pub fn run_all(args: &[&str]) -> Result<(), Error> {
+    let options = parse_options(args)?;
+    check_overrides(ENTRIES, &options.params)?;
+    let config = Config::load(Path::new("aoc.toml"))?;
+    let inputs = Path::new("inputs");
+
+    let mut tasks = Vec::with_capacity(ENTRIES.len());
+    for entry in ENTRIES.iter() {
+        let input = read_input(inputs, entry.day)?;
+        let path = input_path(inputs, entry.day);
+        let params = config.params(entry, &options.params);
+        tasks.push(Task {
+            day: entry.day,
+            part: entry.part,
+            run: Box::new(move || match input {
+                Some(input) => with_params(params, || entry.report(&input)),
+                None => Report::from_run(entry.day, entry.part, || {
+                    Err(Error::msg(format!("no input at {}", path.display())))
+                }),
+            }),
+        });
+    }
+    let parts = tasks
+        .iter()
+        .map(|task| (task.day, task.part))
+        .collect::<Vec<_>>();
+
+    let cpu_start = cpu_time();
+    let start = Instant::now();
+    let outcomes = run_tasks(tasks, &options);
+    let wall = start.elapsed();
+    let cpu = cpu_time().zip(cpu_start).map(|(end, start)| end - start);
+
+    println!(
+        "{:>3} {:>4}  {:<7}  {:>12}  answer",
+        "day", "part", "status", "elapsed"
+    );
+    let mut failed = 0;
+    for ((day, part), (outcome, elapsed)) in parts.iter().zip(outcomes.iter()) {
+        let (status, answer) = match outcome {
+            Outcome::Finished(Report {
+                answer: Some(answer),
+                ..
+            }) => ("ok", answer.as_str()),
+            Outcome::Finished(Report {
+                interrupted: true,
+                error: Some(error),
+                ..
+            }) => {
+                failed += 1;
+                ("timeout", error.as_str())
+            }
+            Outcome::Finished(report) => {
+                failed += 1;
+                // the rest of a multi-line error is printed below the table
+                let error = report.error.as_deref().unwrap_or_default();
+                ("error", error.lines().next().unwrap_or_default())
+            }
+            Outcome::TimedOut => {
+                failed += 1;
+                ("timeout", "-")
+            }
+        };
+        println!(
+            "{:>3} {:>4}  {:<7}  {:>12}  {}",
+            day,
+            part,
+            status,
+            format!("{:.2?}", elapsed),
+            answer
+        );
+    }
+    println!(
+        "wall {:.2?}, cpu {}, {} job(s)",
+        wall,
+        cpu.map_or("unknown".to_owned(), |cpu| format!("{:.2?}", cpu)),
+        options.jobs
+    );
+
+    for ((day, part), (outcome, _)) in parts.iter().zip(outcomes.iter()) {
+        let Outcome::Finished(report) = outcome else {
+            continue;
+        };
+        let error = report.error.as_deref().filter(|error| error.contains('\n'));
+        if report.diagnostics.is_empty() && error.is_none() {
+            continue;
+        }
+        eprintln!("day {} part {}:", day, part);
+        if let Some(error) = error {
+            eprintln!("  {}", error.replace('\n', "\n  "));
+        }
+        for diagnostic in report.diagnostics.iter() {
+            eprintln!(
+                "  [{} {}] {}",
+                diagnostic.level.to_ascii_uppercase(),
+                diagnostic.target,
+                diagnostic.message.replace('\n', "\n  ")
+            );
+        }
+    }
+
+    check_failed(failed)
+}
+
+/// Runs `tasks` on `options.jobs` worker threads, returning each one's outcome and how long it
+/// ran in the order given.
+///
+/// Each task runs under a [`Budget`] of `options.timeout`, so a solver that tracks its loops
+/// stops itself and reports how far it got. One that is still going [`GRACE`] later is
+/// abandoned and its worker replaced, so the rest still get `options.jobs` threads; the abandoned
+/// worker exits instead of taking another task if its part ever ends.
+pub fn run_tasks(tasks: Vec<Task>, options: &Options) -> Vec<(Outcome, Duration)> {
+    let count = tasks.len();
+    let queue = Arc::new(Mutex::new(
+        tasks.into_iter().enumerate().collect::<VecDeque<_>>(),
+    ));
+    let (sender, receiver) = channel();
+
+    for _ in 0..options.jobs.min(count) {
+        spawn_worker(&queue, &sender, options.timeout);
+    }
+
+    let mut outcomes = (0..count).map(|_| None).collect::<Vec<_>>();
+    let mut running = Vec::<(usize, Instant, Budget, Arc<AtomicBool>)>::new();
+    let limit = options.timeout + GRACE;
+    let mut done = 0;
+    while done < count {
+        let wait = running
+            .iter()
+            .map(|(_, started, _, _)| (*started + limit).saturating_duration_since(Instant::now()))
+            .min()
+            .unwrap_or(limit);
+        match receiver.recv_timeout(wait) {
+            Ok(Message::Started(index, budget, abandoned)) => {
+                running.push((index, Instant::now(), budget, abandoned))
+            }
+            Ok(Message::Finished(index, report, elapsed)) => {
+                running.retain(|(running, _, _, _)| *running != index);
+                // a late finish after the task was given up on doesn't count
+                if outcomes[index].is_none() {
+                    outcomes[index] = Some((Outcome::Finished(report), elapsed));
+                    done += 1;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                for (index, _, budget, abandoned) in running
+                    .iter()
+                    .filter(|(_, started, _, _)| *started + limit <= now)
+                {
+                    // in case it checks its budget after all, stop it using a core for nothing
+                    budget.cancel();
+                    abandoned.store(true, Ordering::Relaxed);
+                    outcomes[*index] = Some((Outcome::TimedOut, limit));
+                    done += 1;
+                    spawn_worker(&queue, &sender, options.timeout);
+                }
+                running.retain(|(_, started, _, _)| *started + limit > now);
+            }
+            Err(RecvTimeoutError::Disconnected) => unreachable!("the sender outlives the loop"),
+        }
+    }
+
+    outcomes
+        .into_iter()
+        .map(|outcome| outcome.expect("every task finished or timed out"))
+        .collect()
+}
+
+fn spawn_worker(
+    queue: &Arc<Mutex<VecDeque<(usize, Task)>>>,
+    sender: &Sender<Message>,
+    timeout: Duration,
+) {
+    let queue = Arc::clone(queue);
+    let sender = sender.clone();
+    let abandoned = Arc::new(AtomicBool::new(false));
+    spawn_solver(move || loop {
+        // a replacement has taken this worker's place, so running more would exceed the jobs
+        if abandoned.load(Ordering::Relaxed) {
+            return;
+        }
+        let Some((index, task)) = queue.lock().unwrap_or_else(|e| e.into_inner()).pop_front()
+        else {
+            return;
+        };
+        let budget = Budget::timeout(timeout);
+        let _ = sender.send(Message::Started(
+            index,
+            budget.clone(),
+            Arc::clone(&abandoned),
+        ));
+        let start = Instant::now();
+        let report = run_task(task, budget);
+        // the receiver is gone once every task has an outcome, which is fine
+        let _ = sender.send(Message::Finished(index, report, start.elapsed()));
+    })
+    .expect("can't start a worker thread");
+}
+
+/// Runs `task` under `budget` on this thread, reporting a panic as an error rather than printing
+/// it over the table.
+pub fn run_task(task: Task, budget: Budget) -> Report {
+    let (day, part) = (task.day, task.part);
+    catch_panic(|| with_budget(budget, task.run)).unwrap_or_else(|message| {
+        Report::from_run(day, part, || {
+            Err(Error::msg(format!("panicked: {}", message)))
+        })
+    })
+}
+
+/// CPU time used by the whole process so far, across all threads.
+#[cfg(unix)]
+fn cpu_time() -> Option<Duration> {
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::uninit();
+    // SAFETY: getrusage only writes to the struct it is given
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr()) } != 0 {
+        return None;
+    }
+    // SAFETY: getrusage succeeded, so it filled the struct in
+    let usage = unsafe { usage.assume_init() };
+    let time = |t: libc::timeval| {
+        Duration::from_secs(t.tv_sec as u64) + Duration::from_micros(t.tv_usec as u64)
+    };
+    Some(time(usage.ru_utime) + time(usage.ru_stime))
+}
+
+#[cfg(not(unix))]
+fn cpu_time() -> Option<Duration> {
+    None
+}
+
+pub fn parse_options(mut args: &[&str]) -> Result<Options, Error> {
+    let mut options = Options {
+        jobs: thread::available_parallelism().map_or(1, |jobs| jobs.get()),
+        timeout: DEFAULT_TIMEOUT,
+        params: Params::default(),
+    };
+    loop {
+        match args {
+            [] => break,
+            ["--jobs", jobs, rest @ ..] => {
+                options.jobs = jobs.parse().context("invalid number of jobs")?;
+                if options.jobs == 0 {
+                    return Err(Error::msg("need at least one job"));
+                }
+                args = rest;
+            }
+            ["--timeout", seconds, rest @ ..] => {
+                options.timeout = parse_timeout(seconds)?;
+                args = rest;
+            }
+            ["--param", assignment, rest @ ..] => {
+                options.params.set_assignment(assignment)?;
+                args = rest;
+            }
+            _ => return Err(Error::msg(USAGE)),
+        }
+    }
+    Ok(options)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023_common::{Progress, Run};
+
+    use super::*;
+
+    fn task(day: u8, run: impl FnOnce() -> Result<Run, Error> + Send + 'static) -> Task {
+        Task {
+            day,
+            part: Part::One,
+            run: Box::new(move || Report::from_run(day, Part::One, run)),
+        }
+    }
+
+    fn answer(text: &str) -> Result<Run, Error> {
+        Ok(Run {
+            answer: text.to_owned(),
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+        })
+    }
+
+    #[test]
+    fn tasks_finish_fail_panic_and_time_out() {
+        let tasks = vec![
+            task(1, || answer("42")),
+            task(2, || Err(Error::msg("bad input"))),
+            task(3, || panic!("index out of bounds")),
+            task(4, || {
+                thread::sleep(Duration::from_secs(5));
+                answer("late")
+            }),
+            task(5, || answer("7")),
+            task(6, || {
+                let progress = Progress::new("test", None);
+                loop {
+                    progress.tick()?;
+                }
+            }),
+        ];
+        let options = Options {
+            jobs: 2,
+            timeout: Duration::from_millis(200),
+            params: Params::default(),
+        };
+        let outcomes = run_tasks(tasks, &options);
+
+        let summary = outcomes
+            .iter()
+            .map(|(outcome, _)| match outcome {
+                Outcome::Finished(report) => report
+                    .answer
+                    .clone()
+                    .or(report.error.clone())
+                    .unwrap_or_default(),
+                Outcome::TimedOut => "timeout".to_owned(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                "42",
+                "bad input",
+                "panicked: index out of bounds",
+                "timeout",
+                "7",
+                summary[5].as_str()
+            ]
+        );
+        assert_eq!(outcomes[3].1, options.timeout + GRACE);
+        let Outcome::Finished(report) = &outcomes[5].0 else {
+            panic!("the loop should stop itself");
+        };
+        assert!(report.interrupted);
+        assert!(summary[5].starts_with("timed out after "), "{}", summary[5]);
+    }
+
+    #[test]
+    fn abandoned_workers_take_no_more_tasks() {
+        use std::sync::atomic::AtomicUsize;
+
+        let running = Arc::new(AtomicUsize::new(0));
+        let most = Arc::new(AtomicUsize::new(0));
+        // outlives its timeout and grace, then finishes while the others are still queued
+        let mut tasks = vec![task(1, || {
+            thread::sleep(GRACE + Duration::from_millis(200));
+            answer("late")
+        })];
+        for day in 2..8 {
+            let (running, most) = (Arc::clone(&running), Arc::clone(&most));
+            tasks.push(task(day, move || {
+                most.fetch_max(running.fetch_add(1, Ordering::SeqCst) + 1, Ordering::SeqCst);
+                thread::sleep(Duration::from_millis(100));
+                running.fetch_sub(1, Ordering::SeqCst);
+                answer("ok")
+            }));
+        }
+        let options = Options {
+            jobs: 1,
+            timeout: Duration::ZERO,
+            params: Params::default(),
+        };
+        let outcomes = run_tasks(tasks, &options);
+
+        assert!(matches!(outcomes[0].0, Outcome::TimedOut));
+        assert_eq!(most.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn options_parse_jobs_timeout_and_params() {
+        let options =
+            parse_options(&["--jobs", "3", "--param", "cycles=1000", "--timeout", "1.5"]).unwrap();
+        assert_eq!(options.jobs, 3);
+        assert_eq!(options.timeout, Duration::from_millis(1500));
+        assert_eq!(options.params.get("cycles"), Some("1000"));
+
+        assert!(parse_options(&[]).unwrap().jobs >= 1);
+        assert!(parse_options(&["--jobs", "0"]).is_err());
+        assert!(parse_options(&["--timeout", "-1"]).is_err());
+        assert!(parse_options(&["all"]).is_err());
+        assert!(parse_options(&["--param", "cycles"]).is_err());
+    }
+}
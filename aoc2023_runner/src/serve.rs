@@ -0,0 +1,479 @@
+use std::io::Read;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::mpsc::channel;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Error};
+use aoc2023_common::{parse_timeout, spawn_solver, with_params, Budget, Params, Report};
+use serde_json::json;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::config::Config;
+use crate::parallel::{run_task, Outcome, Task, GRACE};
+use crate::{parse_part, DEFAULT_TIMEOUT, USAGE};
+
+pub struct Options {
+    /// Where to listen; always a loopback address.
+    pub addr: SocketAddr,
+    /// How many requests to solve at once; the rest wait their turn for up to the timeout. A part
+    /// abandoned for running well past the timeout still counts until it really finishes.
+    pub workers: usize,
+    /// The largest input accepted, in bytes.
+    pub max_input: usize,
+    pub timeout: Duration,
+}
+
+/// `aoc serve [--addr HOST:PORT] [--workers N] [--max-input BYTES] [--timeout SECS]`
+///
+/// Serves the solvers over HTTP on this machine, for tools that would rather not run the day
+/// binaries. `POST /days/{day}/parts/{part}` with the puzzle input as the body answers with the
+/// part's [`Report`] as JSON, like `aoc run --json` prints it. Puzzle parameters come from
+/// `aoc.toml`.
+pub fn serve(args: &[&str]) -> Result<(), Error> {
+    let options = parse_options(args)?;
+    let config = Config::load(Path::new("aoc.toml"))?;
+    let service = Service::bind(options, config)?;
+    eprintln!("serving on http://{}", service.addr());
+    service.run();
+    Ok(())
+}
+
+/// The bound server and what it needs to answer requests.
+pub struct Service {
+    server: Server,
+    options: Options,
+    config: Config,
+    slots: Arc<Slots>,
+}
+
+/// How many more solver threads may run, shared with the threads so an abandoned one gives its
+/// slot back only once it ends.
+struct Slots {
+    free: Mutex<usize>,
+    freed: Condvar,
+}
+
+/// A slot taken from [`Slots`], given back when dropped.
+struct Slot(Arc<Slots>);
+
+impl Slots {
+    /// Waits up to `timeout` for a free slot and takes it, or gives up if none frees up.
+    fn take(self: &Arc<Self>, timeout: Duration) -> Option<Slot> {
+        let free = self.free.lock().unwrap_or_else(|e| e.into_inner());
+        let (mut free, _) = self
+            .freed
+            .wait_timeout_while(free, timeout, |free| *free == 0)
+            .unwrap_or_else(|e| e.into_inner());
+        if *free == 0 {
+            return None;
+        }
+        *free -= 1;
+        Some(Slot(Arc::clone(self)))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        *self.0.free.lock().unwrap_or_else(|e| e.into_inner()) += 1;
+        self.0.freed.notify_one();
+    }
+}
+
+impl Service {
+    pub fn bind(options: Options, config: Config) -> Result<Self, Error> {
+        if !options.addr.ip().is_loopback() {
+            return Err(Error::msg(format!(
+                "{} isn't a loopback address; the server only runs locally",
+                options.addr
+            )));
+        }
+        let server = Server::http(options.addr)
+            .map_err(|e| Error::msg(e.to_string()))
+            .with_context(|| format!("can't listen on {}", options.addr))?;
+        let slots = Arc::new(Slots {
+            free: Mutex::new(options.workers),
+            freed: Condvar::new(),
+        });
+        Ok(Self {
+            server,
+            options,
+            config,
+            slots,
+        })
+    }
+
+    /// The address actually listened on, which has the real port if `addr` asked for port 0.
+    pub fn addr(&self) -> SocketAddr {
+        self.server
+            .server_addr()
+            .to_ip()
+            .expect("the server listens on an IP address")
+    }
+
+    /// Answers requests on `options.workers` threads, forever.
+    pub fn run(self) {
+        let service = Arc::new(self);
+        let workers = (0..service.options.workers)
+            .map(|_| {
+                let service = Arc::clone(&service);
+                thread::spawn(move || {
+                    while let Ok(request) = service.server.recv() {
+                        service.respond(request);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for worker in workers {
+            let _ = worker.join();
+        }
+    }
+
+    fn respond(&self, mut request: Request) {
+        let (status, body) = match self.handle(&mut request) {
+            Ok((status, report)) => (status, report.to_json()),
+            Err((status, error)) => (status, json!({ "error": error }).to_string()),
+        };
+        let content_type =
+            Header::from_bytes("Content-Type", "application/json").expect("the header is valid");
+        let response = Response::from_string(body)
+            .with_status_code(status)
+            .with_header(content_type);
+        // the client may have given up waiting, which is its business
+        let _ = request.respond(response);
+    }
+
+    fn handle(&self, request: &mut Request) -> Result<(u16, Report), (u16, String)> {
+        let deadline = Instant::now() + self.options.timeout;
+        let path = request.url().split('?').next().unwrap_or_default();
+        let segments = path.split('/').collect::<Vec<_>>();
+        let (day, part) = match segments.as_slice() {
+            ["", "days", day, "parts", part] => (*day, *part),
+            _ => return Err((404, format!("no such endpoint {}", path))),
+        };
+        let part = parse_part(part).map_err(|e| (404, e.to_string()))?;
+        let entry = day
+            .parse::<u8>()
+            .ok()
+            .and_then(|day| aoc2023_days::find(day, Some(part)).next())
+            .ok_or_else(|| (404, format!("day {} not solved", day)))?;
+        if *request.method() != Method::Post {
+            return Err((405, "use POST with the puzzle input as the body".to_owned()));
+        }
+
+        let too_large = || {
+            (
+                413,
+                format!("the input is over {} bytes", self.options.max_input),
+            )
+        };
+        if request
+            .body_length()
+            .is_some_and(|length| length > self.options.max_input)
+        {
+            return Err(too_large());
+        }
+        let mut input = Vec::new();
+        request
+            .as_reader()
+            .take(self.options.max_input as u64 + 1)
+            .read_to_end(&mut input)
+            .map_err(|e| (400, format!("can't read the input: {}", e)))?;
+        if input.len() > self.options.max_input {
+            return Err(too_large());
+        }
+        let input =
+            String::from_utf8(input).map_err(|_| (400, "the input isn't UTF-8".to_owned()))?;
+
+        let params = self.config.params(entry, &Params::default());
+        let task = Task {
+            day: entry.day,
+            part: entry.part,
+            run: Box::new(move || with_params(params, || entry.report(&input))),
+        };
+        match self.run_with_timeout(task, deadline)? {
+            Outcome::Finished(report) if report.answer.is_some() => Ok((200, report)),
+            Outcome::Finished(report) if report.interrupted => Ok((504, report)),
+            Outcome::Finished(report) => Ok((422, report)),
+            Outcome::TimedOut => Err((
+                504,
+                format!(
+                    "gave up after {:.2?} without an answer",
+                    self.options.timeout + GRACE
+                ),
+            )),
+        }
+    }
+
+    /// Runs `task` on a thread of its own until `deadline`, abandoning it if it is still going
+    /// [`GRACE`] after that, as `aoc run-all` does. The thread holds one of the [`Slots`] until
+    /// it ends. If every slot stays taken until the deadline, the task is turned away with a 503
+    /// rather than left waiting behind solvers that may never end. Time spent waiting for a slot
+    /// comes out of the task's own budget.
+    fn run_with_timeout(&self, task: Task, deadline: Instant) -> Result<Outcome, (u16, String)> {
+        let slot = self
+            .slots
+            .take(deadline.saturating_duration_since(Instant::now()))
+            .ok_or_else(|| {
+                (
+                    503,
+                    format!(
+                        "every solver was still busy after {:.2?}",
+                        self.options.timeout
+                    ),
+                )
+            })?;
+        let budget = Budget::timeout(deadline.saturating_duration_since(Instant::now()));
+        let (sender, receiver) = channel();
+        let task_budget = budget.clone();
+        spawn_solver(move || {
+            let _slot = slot;
+            // nobody is waiting any more if the task was abandoned
+            let _ = sender.send(run_task(task, task_budget));
+        })
+        .map_err(|e| (503, format!("can't start a solver: {}", e)))?;
+        match receiver.recv_timeout((deadline + GRACE).saturating_duration_since(Instant::now())) {
+            Ok(report) => Ok(Outcome::Finished(report)),
+            Err(_) => {
+                // in case it checks its budget after all, stop it using a core for nothing
+                budget.cancel();
+                Ok(Outcome::TimedOut)
+            }
+        }
+    }
+}
+
+fn parse_options(mut args: &[&str]) -> Result<Options, Error> {
+    let mut options = Options {
+        addr: SocketAddr::from(([127, 0, 0, 1], 2023)),
+        workers: thread::available_parallelism().map_or(1, |workers| workers.get()),
+        max_input: 1 << 20,
+        timeout: DEFAULT_TIMEOUT,
+    };
+    loop {
+        match args {
+            [] => break,
+            ["--addr", addr, rest @ ..] => {
+                options.addr = addr.parse().context("invalid address")?;
+                args = rest;
+            }
+            ["--workers", workers, rest @ ..] => {
+                options.workers = workers.parse().context("invalid number of workers")?;
+                if options.workers == 0 {
+                    return Err(Error::msg("need at least one worker"));
+                }
+                args = rest;
+            }
+            ["--max-input", bytes, rest @ ..] => {
+                options.max_input = bytes.parse().context("invalid input size")?;
+                args = rest;
+            }
+            ["--timeout", seconds, rest @ ..] => {
+                options.timeout = parse_timeout(seconds)?;
+                args = rest;
+            }
+            _ => return Err(Error::msg(USAGE)),
+        }
+    }
+    Ok(options)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023_common::{Part, Progress};
+    use serde_json::Value;
+
+    use super::*;
+
+    /// Starts a server on a free port in this process, returning its base URL.
+    fn start(max_input: usize, timeout: Duration) -> String {
+        let options = Options {
+            addr: SocketAddr::from(([127, 0, 0, 1], 0)),
+            workers: 2,
+            max_input,
+            timeout,
+        };
+        let service = Service::bind(options, Config::default()).unwrap();
+        let base_url = format!("http://{}", service.addr());
+        thread::spawn(move || service.run());
+        base_url
+    }
+
+    fn post(url: &str, body: &str) -> (u16, Value) {
+        let (status, response) = match ureq::post(url).send_string(body) {
+            Ok(response) => (response.status(), response),
+            Err(ureq::Error::Status(status, response)) => (status, response),
+            Err(e) => panic!("{}", e),
+        };
+        let body = response.into_string().unwrap();
+        (status, serde_json::from_str(&body).unwrap())
+    }
+
+    #[test]
+    fn solves_posted_inputs() {
+        let base_url = start(1 << 20, Duration::from_secs(60));
+        let example = include_str!("../../aoc2023_01/tests/examples/example.txt");
+
+        let (status, report) = post(&format!("{}/days/1/parts/1", base_url), example);
+        assert_eq!(status, 200);
+        assert_eq!(report["day"], 1);
+        assert_eq!(report["part"], 1);
+        assert_eq!(report["answer"], "142");
+        assert!(report["elapsed"].is_f64());
+        assert!(report["diagnostics"].is_array());
+
+        let (status, report) = post(&format!("{}/days/1/parts/1", base_url), "1abc\nxyz\n");
+        assert_eq!(status, 422);
+        assert_eq!(report["answer"], Value::Null);
+        assert!(
+            report["error"].as_str().unwrap().contains("xyz"),
+            "{}",
+            report
+        );
+    }
+
+    #[test]
+    fn rejects_bad_requests() {
+        let base_url = start(16, Duration::from_secs(60));
+
+        for (path, status) in [
+            ("/days/1", 404),
+            ("/days/1/parts/3", 404),
+            ("/days/99/parts/1", 404),
+        ] {
+            let (actual, body) = post(&format!("{}{}", base_url, path), "");
+            assert_eq!(actual, status, "{}", path);
+            assert!(body["error"].is_string());
+        }
+
+        match ureq::get(&format!("{}/days/1/parts/1", base_url)).call() {
+            Err(ureq::Error::Status(status, _)) => assert_eq!(status, 405),
+            other => panic!("{:?}", other.map(|response| response.status())),
+        }
+
+        let (status, body) = post(&format!("{}/days/1/parts/1", base_url), &"1".repeat(17));
+        assert_eq!(status, 413);
+        assert_eq!(body["error"], "the input is over 16 bytes");
+    }
+
+    #[test]
+    fn requests_out_of_time_are_interrupted() {
+        let base_url = start(1 << 20, Duration::ZERO);
+        let example = include_str!("../../aoc2023_14_2/tests/examples/example.txt");
+
+        let (status, report) = post(&format!("{}/days/14/parts/2", base_url), example);
+        assert_eq!(status, 504);
+        assert_eq!(report["interrupted"], true);
+    }
+
+    #[test]
+    fn large_inputs_leave_the_server_up() {
+        let base_url = start(1 << 20, Duration::from_secs(60));
+        let puzzle = aoc2023_generate::find(16)
+            .unwrap()
+            .generate(0, &aoc2023_generate::Options::new().set("size", 700))
+            .unwrap();
+
+        let (status, report) = post(&format!("{}/days/16/parts/1", base_url), &puzzle.input);
+        assert_eq!(status, 200, "{}", report);
+
+        let example = include_str!("../../aoc2023_01/tests/examples/example.txt");
+        let (status, report) = post(&format!("{}/days/1/parts/1", base_url), example);
+        assert_eq!(status, 200);
+        assert_eq!(report["answer"], "142");
+    }
+
+    #[test]
+    fn slots_are_waited_for() {
+        let slots = Arc::new(Slots {
+            free: Mutex::new(1),
+            freed: Condvar::new(),
+        });
+        let slot = slots.take(Duration::ZERO).unwrap();
+        assert!(slots.take(Duration::from_millis(10)).is_none());
+        let (sender, receiver) = channel();
+        let waiter = Arc::clone(&slots);
+        thread::spawn(move || {
+            let _slot = waiter.take(Duration::from_secs(10)).unwrap();
+            sender.send(()).unwrap();
+        });
+        assert!(receiver.recv_timeout(Duration::from_millis(100)).is_err());
+        drop(slot);
+        receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+    }
+
+    #[test]
+    fn waiting_for_a_slot_counts_against_the_timeout() {
+        let options = Options {
+            addr: SocketAddr::from(([127, 0, 0, 1], 0)),
+            workers: 1,
+            max_input: 1 << 20,
+            timeout: Duration::from_millis(500),
+        };
+        let service = Service::bind(options, Config::default()).unwrap();
+        let slot = service.slots.take(Duration::ZERO).unwrap();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(300));
+            drop(slot);
+        });
+        // a solver that runs until its budget stops it
+        let task = Task {
+            day: 1,
+            part: Part::One,
+            run: Box::new(|| {
+                let progress = Progress::new(module_path!(), None);
+                while progress.tick().is_ok() {
+                    thread::sleep(Duration::from_millis(1));
+                }
+                Report {
+                    day: 1,
+                    part: 1,
+                    answer: None,
+                    error: None,
+                    interrupted: true,
+                    elapsed: 0.0,
+                    diagnostics: Vec::new(),
+                }
+            }),
+        };
+
+        let started = Instant::now();
+        let outcome = service.run_with_timeout(task, started + service.options.timeout);
+        assert!(matches!(outcome, Ok(Outcome::Finished(_))));
+        assert!(started.elapsed() < Duration::from_millis(800));
+    }
+
+    #[test]
+    fn busy_workers_turn_requests_away() {
+        let options = Options {
+            addr: SocketAddr::from(([127, 0, 0, 1], 0)),
+            workers: 2,
+            max_input: 1 << 20,
+            timeout: Duration::from_millis(100),
+        };
+        let service = Service::bind(options, Config::default()).unwrap();
+        // a solver that never checks its budget, so it never gives its slot back
+        let never_ending = || Task {
+            day: 1,
+            part: Part::One,
+            run: Box::new(|| loop {
+                thread::park();
+            }),
+        };
+
+        for _ in 0..2 {
+            let outcome =
+                service.run_with_timeout(never_ending(), Instant::now() + service.options.timeout);
+            assert!(matches!(outcome, Ok(Outcome::TimedOut)));
+        }
+        match service.run_with_timeout(never_ending(), Instant::now() + service.options.timeout) {
+            Err((status, error)) => {
+                assert_eq!(status, 503);
+                assert_eq!(error, "every solver was still busy after 100.00ms");
+            }
+            Ok(_) => panic!("ran with every worker busy"),
+        }
+    }
+}
@@ -0,0 +1,338 @@
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, write};
+use std::io;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Error};
+use aoc2023_common::{parse_timeout, with_budget, Budget, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, Verdict};
+use crate::config::Config;
+use crate::{parse_day, parse_part, read_day_input, DEFAULT_TIMEOUT, USAGE};
+
+/// Every answer submitted so far and what the site said about it, so a known-wrong answer is
+/// never sent twice. Kept as TOML keyed by day and then part like `answers.toml`:
+///
+/// ```toml
+/// wait_until = 1701763200
+///
+/// [days.5.1]
+/// too_high = ["462648397"]
+/// too_low = ["2640"]
+/// wrong = []
+/// correct = "388071289"
+/// ```
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Ledger {
+    /// Seconds since the Unix epoch before which the site won't take another answer.
+    wait_until: u64,
+    days: BTreeMap<String, BTreeMap<String, Submissions>>,
+}
+
+/// What has been submitted for one part.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+struct Submissions {
+    too_high: Vec<String>,
+    too_low: Vec<String>,
+    wrong: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    correct: Option<String>,
+}
+
+/// Whether an answer is worth sending.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Check {
+    Submit,
+    /// Already accepted, so there is nothing to do.
+    AlreadyCorrect,
+}
+
+impl Ledger {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match read_to_string(path) {
+            Ok(text) => toml::from_str(&text).with_context(|| path.display().to_string()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::new(e).context(path.display().to_string())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        write(path, toml::to_string(self)?).with_context(|| path.display().to_string())
+    }
+
+    /// Decides whether `answer` should be sent at `now` (seconds since the Unix epoch), or why
+    /// not. Answers already rejected are refused, and so are numbers the site's hints rule out.
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: u64) -> Result<Check, Error> {
+        let refuse = |reason: String| {
+            Err(Error::msg(format!(
+                "day {} part {}: not submitting {}: {}",
+                day, part, answer, reason
+            )))
+        };
+        let submissions = self.get(day, part);
+        if let Some(submissions) = submissions {
+            match &submissions.correct {
+                Some(correct) if correct == answer => return Ok(Check::AlreadyCorrect),
+                Some(correct) => return refuse(format!("{} was already accepted", correct)),
+                None => {}
+            }
+            if submissions.wrong.iter().any(|wrong| wrong == answer) {
+                return refuse("it was already rejected".to_owned());
+            }
+            if let Ok(value) = answer.parse::<i128>() {
+                let numbers = |answers: &[String]| {
+                    answers
+                        .iter()
+                        .filter_map(|answer| answer.parse::<i128>().ok())
+                        .collect::<Vec<_>>()
+                };
+                if let Some(high) = numbers(&submissions.too_high).into_iter().min() {
+                    if value >= high {
+                        return refuse(format!("{} was already too high", high));
+                    }
+                }
+                if let Some(low) = numbers(&submissions.too_low).into_iter().max() {
+                    if value <= low {
+                        return refuse(format!("{} was already too low", low));
+                    }
+                }
+            }
+        }
+        if now < self.wait_until {
+            return refuse(format!(
+                "the site wants another {}s before the next answer",
+                self.wait_until - now
+            ));
+        }
+        Ok(Check::Submit)
+    }
+
+    /// Notes the verdict on `answer` and how long to wait before the next one.
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: Part,
+        answer: &str,
+        verdict: Verdict,
+        wait: Duration,
+        now: u64,
+    ) {
+        self.wait_until = now + wait.as_secs();
+        let submissions = self
+            .days
+            .entry(day.to_string())
+            .or_default()
+            .entry(part.to_string())
+            .or_default();
+        let answer = answer.to_owned();
+        match verdict {
+            Verdict::Correct => submissions.correct = Some(answer),
+            Verdict::TooHigh => submissions.too_high.push(answer),
+            Verdict::TooLow => submissions.too_low.push(answer),
+            Verdict::Wrong => submissions.wrong.push(answer),
+            Verdict::TooSoon(_) | Verdict::WrongLevel => {}
+        }
+    }
+
+    fn get(&self, day: u8, part: Part) -> Option<&Submissions> {
+        self.days.get(&day.to_string())?.get(&part.to_string())
+    }
+}
+
+/// `aoc submit <day> <part> [answer] [--timeout SECS]`
+///
+/// Submits `answer`, or else the runner's answer for the day's input, unless the ledger in
+/// `submissions.toml` already knows it to be wrong or the site's cooldown hasn't passed. The
+/// runner gives up on the part after the timeout.
+pub fn submit(mut args: &[&str]) -> Result<(), Error> {
+    let mut timeout = DEFAULT_TIMEOUT;
+    if let [rest @ .., "--timeout", seconds] = args {
+        timeout = parse_timeout(seconds)?;
+        args = rest;
+    }
+    let (day, part, answer) = match args {
+        [day, part] => (parse_day(day)?, parse_part(part)?, None),
+        [day, part, answer] => (parse_day(day)?, parse_part(part)?, Some(answer.to_string())),
+        _ => return Err(Error::msg(USAGE)),
+    };
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let input = read_day_input(day)?;
+            let entry = aoc2023_days::find(day, Some(part))
+                .next()
+                .with_context(|| format!("day {} part {} not solved", day, part))?;
+            with_budget(Budget::timeout(timeout), || entry.run(&input))
+                .with_context(|| format!("day {} part {}", day, part))?
+                .answer
+        }
+    };
+
+    let config = Config::load(Path::new("aoc.toml"))?;
+    let client = Client::new(&config.site)?;
+    let path = Path::new("submissions.toml");
+    let mut ledger = Ledger::load(path)?;
+    let verdict = submit_with(&client, &mut ledger, day, part, &answer, unix_now())?;
+    ledger.save(path)?;
+    report(day, part, &answer, verdict)
+}
+
+/// Checks `answer` against the ledger and submits it if it might be right, recording the
+/// verdict. Returns `None` if the ledger already had it as correct.
+fn submit_with(
+    client: &Client,
+    ledger: &mut Ledger,
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<Option<Verdict>, Error> {
+    if ledger.check(day, part, answer, now)? == Check::AlreadyCorrect {
+        return Ok(None);
+    }
+    let (verdict, page) = client.submit_answer(day, part, answer)?;
+    ledger.record(day, part, answer, verdict, verdict.wait(&page), now);
+    Ok(Some(verdict))
+}
+
+fn report(day: u8, part: Part, answer: &str, verdict: Option<Verdict>) -> Result<(), Error> {
+    let outcome = match verdict {
+        None => Ok("was already accepted".to_owned()),
+        Some(Verdict::Correct) => Ok("is correct".to_owned()),
+        Some(Verdict::TooHigh) => Err("is too high".to_owned()),
+        Some(Verdict::TooLow) => Err("is too low".to_owned()),
+        Some(Verdict::Wrong) => Err("is wrong".to_owned()),
+        Some(Verdict::TooSoon(wait)) => Err(format!(
+            "wasn't checked; the site wants another {}s first",
+            wait.as_secs()
+        )),
+        Some(Verdict::WrongLevel) => {
+            Err("wasn't checked; the part is already solved or still locked".to_owned())
+        }
+    };
+    match outcome {
+        Ok(message) => {
+            println!("day {} part {}: {} {}", day, part, answer, message);
+            Ok(())
+        }
+        Err(message) => Err(Error::msg(format!(
+            "day {} part {}: {} {}",
+            day, part, answer, message
+        ))),
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    use tiny_http::{Response, Server};
+
+    use super::*;
+
+    /// A stand-in for the puzzle site whose only answer is day 5 part 1 = 35, counting the
+    /// answers it gets.
+    fn serve() -> (Client, Arc<AtomicUsize>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let mut config = Config::default();
+        config.site.base_url = format!("http://{}", server.server_addr());
+        config.site.session = Some("token".to_owned());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut form = String::new();
+                request.as_reader().read_to_string(&mut form).unwrap();
+                let answer = form
+                    .strip_prefix("level=1&answer=")
+                    .and_then(|answer| answer.parse::<i64>().ok());
+                let message = match (request.url(), answer) {
+                    ("/2023/day/5/answer", Some(35)) => "That's the right answer!".to_owned(),
+                    ("/2023/day/5/answer", Some(answer)) => format!(
+                        "That's not the right answer; your answer is too {}. Please wait one minute before trying again.",
+                        if answer > 35 { "high" } else { "low" }
+                    ),
+                    _ => "You don't seem to be solving the right level.".to_owned(),
+                };
+                let page = format!("<main><article><p>{}</p></article></main>", message);
+                let _ = request.respond(Response::from_string(page));
+            }
+        });
+        (Client::new(&config.site).unwrap(), requests)
+    }
+
+    #[test]
+    fn ledger_stops_known_wrong_answers_and_waits_out_cooldowns() {
+        let (client, requests) = serve();
+        let mut ledger = Ledger::default();
+        let mut submit = |answer: &str, now: u64| {
+            submit_with(&client, &mut ledger, 5, Part::One, answer, now).map_err(|e| e.to_string())
+        };
+
+        assert_eq!(submit("100", 1000), Ok(Some(Verdict::TooHigh)));
+        assert_eq!(
+            submit("10", 1010),
+            Err("day 5 part 1: not submitting 10: the site wants another 50s before the next answer".to_owned())
+        );
+        assert_eq!(submit("10", 1060), Ok(Some(Verdict::TooLow)));
+        assert_eq!(
+            submit("100", 2000),
+            Err("day 5 part 1: not submitting 100: 100 was already too high".to_owned())
+        );
+        assert_eq!(
+            submit("200", 2000),
+            Err("day 5 part 1: not submitting 200: 100 was already too high".to_owned())
+        );
+        assert_eq!(
+            submit("5", 2000),
+            Err("day 5 part 1: not submitting 5: 10 was already too low".to_owned())
+        );
+        assert_eq!(submit("35", 2000), Ok(Some(Verdict::Correct)));
+        assert_eq!(submit("35", 2000), Ok(None));
+        assert_eq!(
+            submit("36", 2000),
+            Err("day 5 part 1: not submitting 36: 35 was already accepted".to_owned())
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn ledger_round_trips() {
+        let mut ledger = Ledger::default();
+        ledger.record(
+            5,
+            Part::Two,
+            "abc",
+            Verdict::Wrong,
+            Duration::from_secs(60),
+            100,
+        );
+        ledger.record(5, Part::Two, "46", Verdict::Correct, Duration::ZERO, 200);
+
+        let text = toml::to_string(&ledger).unwrap();
+        let ledger = toml::from_str::<Ledger>(&text).unwrap();
+        assert_eq!(ledger.wait_until, 200);
+        assert!(ledger.check(5, Part::Two, "abc", 300).is_err());
+        assert_eq!(
+            ledger.check(5, Part::Two, "46", 300).unwrap(),
+            Check::AlreadyCorrect
+        );
+        assert_eq!(
+            ledger.check(5, Part::One, "46", 300).unwrap(),
+            Check::Submit
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Error;
+use aoc2023_common::{catch_panic, parse_timeout, with_budget, Budget, Run};
+use aoc2023_days::{Entry, ENTRIES};
+
+use crate::answers::Answers;
+use crate::{check_failed, read_input, DEFAULT_TIMEOUT, USAGE};
+
+enum Status {
+    Error,
+    Fail,
+    Missing,
+    NoInput,
+    Pass,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // pad so the table columns line up
+        f.pad(match self {
+            Status::Error => "error",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+            Status::NoInput => "no input",
+            Status::Pass => "pass",
+        })
+    }
+}
+
+/// `aoc verify [answers.toml] [--timeout SECS]`
+///
+/// Runs every solver on its saved input and reports whether it matches the known answer.
+///
+/// Parts without a recorded answer or without an input are reported but don't count as
+/// failures. A part still going after the timeout is stopped and counts as an error, as does one
+/// that panics.
+pub fn verify(mut args: &[&str]) -> Result<(), Error> {
+    let mut answers = Path::new("answers.toml");
+    let mut timeout = DEFAULT_TIMEOUT;
+    loop {
+        match args {
+            [] => break,
+            ["--timeout", seconds, rest @ ..] => {
+                timeout = parse_timeout(seconds)?;
+                args = rest;
+            }
+            [path, rest @ ..] if !path.starts_with("--") => {
+                answers = Path::new(path);
+                args = rest;
+            }
+            _ => return Err(Error::msg(USAGE)),
+        }
+    }
+    verify_with(Path::new("inputs"), answers, timeout)
+}
+
+fn verify_with(inputs: &Path, answers: &Path, timeout: Duration) -> Result<(), Error> {
+    let answers = Answers::load(answers)?;
+
+    let mut failed = 0;
+    let mut errors = Vec::new();
+
+    println!(
+        "{:>3} {:>4}  {:<8}  {:<20}  actual",
+        "day", "part", "status", "expected"
+    );
+    for entry in ENTRIES.iter() {
+        let expected = answers.get(entry.day, entry.part);
+        let (status, actual) = match read_input(inputs, entry.day)? {
+            None => (Status::NoInput, String::new()),
+            Some(input) => check(expected, timeout, || entry.run(&input)),
+        };
+        if matches!(status, Status::Error | Status::Fail) {
+            failed += 1;
+        }
+        println!("{}", row(entry, &status, expected, &actual));
+        // the rest of a multi-line error is printed below the table
+        if actual.contains('\n') {
+            errors.push((entry, actual));
+        }
+    }
+
+    for (entry, error) in errors {
+        eprintln!("day {} part {}:", entry.day, entry.part);
+        eprintln!("  {}", error.replace('\n', "\n  "));
+    }
+
+    check_failed(failed)
+}
+
+/// Runs one part under the timeout and compares its answer with `expected`, turning an error or
+/// a panic into a [`Status::Error`] with the message as the actual answer.
+fn check<F>(expected: Option<&str>, timeout: Duration, run: F) -> (Status, String)
+where
+    F: FnOnce() -> Result<Run, Error>,
+{
+    match catch_panic(|| with_budget(Budget::timeout(timeout), run)) {
+        Ok(Ok(run)) => match expected {
+            Some(expected) if expected == run.answer => (Status::Pass, run.answer),
+            Some(_) => (Status::Fail, run.answer),
+            None => (Status::Missing, run.answer),
+        },
+        Ok(Err(e)) => (Status::Error, format!("{:#}", e)),
+        Err(message) => (Status::Error, format!("panicked: {}", message)),
+    }
+}
+
+/// One line of the table, with only the first line of a multi-line error.
+fn row(entry: &Entry, status: &Status, expected: Option<&str>, actual: &str) -> String {
+    format!(
+        "{:>3} {:>4}  {:<8}  {:<20}  {}",
+        entry.day,
+        entry.part,
+        status,
+        expected.unwrap_or("-"),
+        actual.lines().next().unwrap_or_default()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panics_are_errors() {
+        let (status, actual) = check(Some("1"), DEFAULT_TIMEOUT, || panic!("index out of bounds"));
+        assert!(matches!(status, Status::Error));
+        assert_eq!(actual, "panicked: index out of bounds");
+    }
+
+    #[test]
+    fn rows_show_the_first_line_of_an_error() {
+        let (status, actual) = check(None, DEFAULT_TIMEOUT, || {
+            Err(Error::msg("bad input\n  on line 3"))
+        });
+        assert_eq!(
+            row(&ENTRIES[0], &status, None, &actual),
+            "  1    1  error     -                     bad input"
+        );
+    }
+}
@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc2023_common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = aoc2023_20::Day20::parse(input);
+    let _ = aoc2023_20_2::Day20::parse(input);
+});